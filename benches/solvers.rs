@@ -0,0 +1,82 @@
+//  SOLVERS.rs
+//    by Lut99
+// 
+//  Created:
+//    16 Oct 2026, 11:03:28
+//  Last edited:
+//    16 Oct 2026, 12:21:13
+//  Auto updated?
+//    Yes
+// 
+//  Description:
+//!   Benchmarks the various [`Solver`]s on the puzzles in the test
+//!   fixtures.
+//! 
+//!   Run with `cargo bench --features bench`.
+// 
+
+use std::time::Duration;
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+
+use sudoku_solver::solvers::{BacktrackingSolver, BruteForceSolver, ConstraintPropagationSolver, Solver, StochasticSolver};
+use sudoku_solver::sudoku::Sudoku;
+use sudoku_solver::utils::{load_sudoku, PrettyError as _};
+
+
+/***** HELPER FUNCTIONS *****/
+/// Loads the puzzles to benchmark with.
+/// 
+/// # Returns
+/// A list of (name, [`Sudoku`]) pairs read from the test fixtures.
+fn puzzles() -> Vec<(String, Sudoku)> {
+    let mut puzzles: Vec<(String, Sudoku)> = vec![];
+    for path in [ "./tests/example.sdk", "./tests/example.sdm" ] {
+        let sudokus: Vec<Sudoku> = load_sudoku(path).unwrap_or_else(|err| panic!("Failed to load Sudoku file '{path}': {}", err.pretty()));
+        puzzles.extend(sudokus.into_iter().enumerate().map(|(i, s)| (format!("{} ({})", path.trim_start_matches("./tests/"), i + 1), s)));
+    }
+    puzzles
+}
+
+/// Benchmarks a single solver on all puzzles.
+/// 
+/// # Arguments
+/// - `c`: The [`Criterion`] instance to add the benchmark group to.
+/// - `name`: The name of the benchmark group.
+/// - `new_solver`: A function that creates a fresh instance of the [`Solver`] to benchmark.
+fn bench_solver<S: Solver>(c: &mut Criterion, name: &str, mut new_solver: impl FnMut() -> S) {
+    let mut group = c.benchmark_group(name);
+    group.sample_size(10);
+    group.measurement_time(Duration::from_secs(10));
+    for (pname, sudoku) in puzzles() {
+        group.bench_with_input(BenchmarkId::from_parameter(pname), &sudoku, |b, sudoku| {
+            let mut solver: S = new_solver();
+            b.iter(|| solver.run(*sudoku))
+        });
+    }
+    group.finish();
+}
+
+
+
+
+
+/***** BENCHMARKS *****/
+/// Benchmarks the [`BruteForceSolver`].
+fn brute_force(c: &mut Criterion) { bench_solver(c, "brute_force", BruteForceSolver::new); }
+
+/// Benchmarks the [`BacktrackingSolver`].
+fn backtracking(c: &mut Criterion) { bench_solver(c, "backtracking", BacktrackingSolver::new); }
+
+/// Benchmarks the [`ConstraintPropagationSolver`].
+fn constraint_propagation(c: &mut Criterion) { bench_solver(c, "constraint_propagation", ConstraintPropagationSolver::new); }
+
+/// Benchmarks the [`StochasticSolver`].
+/// 
+/// Uses a fixed seed to make the runs comparable.
+fn stochastic(c: &mut Criterion) { bench_solver(c, "stochastic", || StochasticSolver::with_seed(42)); }
+
+
+
+criterion_group!(benches, brute_force, backtracking, constraint_propagation, stochastic);
+criterion_main!(benches);
@@ -4,7 +4,7 @@
 //  Created:
 //    10 Aug 2023, 23:23:58
 //  Last edited:
-//    11 Aug 2023, 15:04:06
+//    16 Oct 2026, 12:38:48
 //  Auto updated?
 //    Yes
 // 
@@ -15,18 +15,233 @@
 use std::error;
 use std::fmt::{Display, Formatter, Result as FResult};
 use std::io::{self, Stdout};
-use std::time::Duration;
+use std::path::Path;
+use std::time::{Duration, Instant};
 
 use crossterm::execute;
 use crossterm::event::{self, Event, KeyCode};
 use crossterm::terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen};
 use log::warn;
 use ratatui::{Frame, Terminal};
-use ratatui::backend::CrosstermBackend;
-use ratatui::widgets::Paragraph;
+use ratatui::backend::{Backend, CrosstermBackend};
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::widgets::{Block, Borders, List, ListItem, Paragraph};
 
 use crate::solvers::Solver;
-use crate::sudoku::Sudoku;
+use crate::spec::FileType;
+use crate::sudoku::{InvalidReason, Sudoku, SudokuCandidateFormatter};
+use crate::utils::{save_sudoku_of_type, SaveError};
+
+
+/***** TESTS *****/
+#[cfg(test)]
+mod tests {
+    use crate::utils::{load_sudoku, PrettyError as _};
+    use super::*;
+
+    #[test]
+    fn test_assist_state() {
+        let puzzle: Sudoku = load_sudoku("./tests/example.sdk").unwrap_or_else(|err| panic!("Failed to load Sudoku: {}", err.pretty())).swap_remove(0);
+        let solution: Sudoku = crate::solvers::BruteForceSolver::new().run(puzzle);
+        let mut state: AssistState = AssistState::new(puzzle, solution);
+        assert_eq!(state.cursor, (0, 0));
+
+        // The cursor cannot leave the Sudoku
+        assert!(state.handle_key(KeyCode::Up));
+        assert!(state.handle_key(KeyCode::Left));
+        assert_eq!(state.cursor, (0, 0));
+
+        // Revealing a given does nothing
+        assert!(state.handle_key(KeyCode::Char('h')));
+        assert_eq!(state.sudoku, puzzle);
+
+        // Revealing an empty cell fills in the solution
+        assert!(state.handle_key(KeyCode::Right));
+        assert_eq!(state.cursor, (1, 0));
+        assert_eq!(state.sudoku.rows[0][1], None);
+        assert!(state.handle_key(KeyCode::Char('h')));
+        assert_eq!(state.sudoku.rows[0][1], solution.rows[0][1]);
+        for y in 0..9 {
+            for x in 0..9 {
+                if (x, y) != (1, 0) { assert_eq!(state.sudoku.rows[y][x], puzzle.rows[y][x]); }
+            }
+        }
+
+        // Candidates can be toggled, and are shown for the cell under the cursor
+        assert!(state.handle_key(KeyCode::Down));
+        assert!(state.handle_key(KeyCode::Char('c')));
+        assert!(state.show_candidates);
+        assert!(state.render().contains(&format!("Candidates of cell (2,2): {:?}", puzzle.candidates(1, 1))));
+        assert!(state.render().starts_with(&state.sudoku.pencil_marks().to_string()));
+
+        // The pencil marks only show cells with few enough candidates, if told to do so
+        state.max_candidates = Some(2);
+        assert!(state.render().starts_with(&state.sudoku.pencil_marks().max_candidates(2).to_string()));
+        assert_ne!(state.sudoku.pencil_marks().max_candidates(2).to_string(), state.sudoku.pencil_marks().to_string());
+        assert!(state.handle_key(KeyCode::Char('c')));
+        assert!(!state.show_candidates);
+
+        // Quitting is quitting
+        assert!(!state.handle_key(KeyCode::Char('q')));
+    }
+
+    #[test]
+    fn test_assist_save() {
+        let puzzle: Sudoku = load_sudoku("./tests/example.sdk").unwrap_or_else(|err| panic!("Failed to load Sudoku: {}", err.pretty())).swap_remove(0);
+        let solution: Sudoku = crate::solvers::BruteForceSolver::new().run(puzzle);
+        let mut state: AssistState = AssistState::new(puzzle, solution);
+        assert!(state.handle_key(KeyCode::Right));
+        assert!(state.handle_key(KeyCode::Char('h')));
+
+        // Pressing 's' prompts for a filename, in which keys are typed instead of handled
+        let path: std::path::PathBuf = std::env::temp_dir().join(format!("sudoku_solver_test_assist_save_{}.ss", std::process::id()));
+        assert!(state.handle_key(KeyCode::Char('s')));
+        for c in format!("{}x", path.display()).chars() { assert!(state.handle_key(KeyCode::Char(c))); }
+        assert!(state.handle_key(KeyCode::Backspace));
+        assert!(state.handle_key(KeyCode::Char('q')));
+        assert!(state.handle_key(KeyCode::Backspace));
+        assert!(state.render().contains(&format!("Save as (press 'Enter' to save or 'Esc' to cancel): {}_", path.display())));
+
+        // Pressing enter writes the Sudoku as the user sees it
+        assert!(state.handle_key(KeyCode::Enter));
+        assert_eq!(state.prompt, None);
+        assert_eq!(state.status, Some(format!("Saved to '{}'", path.display())));
+        let saved: Sudoku = load_sudoku(&path).unwrap_or_else(|err| panic!("Failed to load saved Sudoku: {}", err.pretty())).swap_remove(0);
+        std::fs::remove_file(&path).unwrap_or_else(|err| panic!("Failed to remove '{}': {err}", path.display()));
+        assert_eq!(saved, state.sudoku);
+        assert_ne!(saved, puzzle);
+
+        // Escape cancels
+        assert!(state.handle_key(KeyCode::Char('s')));
+        assert!(state.handle_key(KeyCode::Esc));
+        assert_eq!(state.prompt, None);
+        assert!(!state.handle_key(KeyCode::Char('q')));
+    }
+
+    #[test]
+    fn test_assist_save_type() {
+        let puzzle: Sudoku = load_sudoku("./tests/example.sdk").unwrap_or_else(|err| panic!("Failed to load Sudoku: {}", err.pretty())).swap_remove(0);
+        let solution: Sudoku = crate::solvers::BruteForceSolver::new().run(puzzle);
+        let mut state: AssistState = AssistState::new(puzzle, solution);
+        assert!(state.handle_key(KeyCode::Right));
+        assert!(state.handle_key(KeyCode::Char('h')));
+
+        // The type is chosen by the extension, such that the file can be loaded again
+        for ext in [ "sdk", "txt", "json" ] {
+            let path: std::path::PathBuf = std::env::temp_dir().join(format!("sudoku_solver_test_assist_save_type_{}.{ext}", std::process::id()));
+            state.save(&path).unwrap_or_else(|err| panic!("Failed to save Sudoku to '{}': {}", path.display(), err.pretty()));
+            let saved: Sudoku = load_sudoku(&path).unwrap_or_else(|err| panic!("Failed to load saved Sudoku: {}", err.pretty())).swap_remove(0);
+            std::fs::remove_file(&path).unwrap_or_else(|err| panic!("Failed to remove '{}': {err}", path.display()));
+            assert_eq!(saved, state.sudoku);
+        }
+
+        // Unknown or unwritable extensions show an error instead
+        for (name, err) in [ ("puzzle", "has no extension"), ("puzzle.foo", "is unknown"), ("puzzle.csv", "is not supported") ] {
+            assert!(state.handle_key(KeyCode::Char('s')));
+            for c in name.chars() { assert!(state.handle_key(KeyCode::Char(c))); }
+            assert!(state.handle_key(KeyCode::Enter));
+            let status: &str = state.status.as_deref().unwrap();
+            assert!(status.starts_with(&format!("Failed to save to '{name}'")) && status.contains(err), "Unexpected status '{status}'");
+            assert!(!std::path::Path::new(name).exists());
+        }
+    }
+
+    #[test]
+    fn test_remaining_pause() {
+        assert_eq!(remaining_pause(Duration::ZERO, Duration::from_secs(2)), Some(Duration::from_secs(2)));
+        assert_eq!(remaining_pause(Duration::from_millis(500), Duration::from_secs(2)), Some(Duration::from_millis(1500)));
+        // Once the pause is over, we advance
+        assert_eq!(remaining_pause(Duration::from_secs(2), Duration::from_secs(2)), None);
+        assert_eq!(remaining_pause(Duration::from_secs(3), Duration::from_secs(2)), None);
+        assert_eq!(remaining_pause(Duration::ZERO, Duration::ZERO), None);
+    }
+
+    #[test]
+    fn test_solve_restart() {
+        let puzzle: Sudoku = load_sudoku("./tests/example.sdm").unwrap_or_else(|err| panic!("Failed to load Sudoku: {}", err.pretty())).swap_remove(3);
+        let mut solver: crate::solvers::BruteForceSolver = crate::solvers::BruteForceSolver::new();
+
+        // Keys map to the actions
+        assert_eq!(SolveAction::from_key(KeyCode::Char('r')), SolveAction::Restart);
+        assert_eq!(SolveAction::from_key(KeyCode::Char('q')), SolveAction::Quit);
+        assert_eq!(SolveAction::from_key(KeyCode::Char('x')), SolveAction::Continue);
+        assert_eq!(SolveAction::from_key(KeyCode::Enter), SolveAction::Continue);
+
+        // Restarting begins from the initial state again, and then solves normally
+        let mut attempts: Vec<Sudoku> = vec![];
+        let solution: Option<Sudoku> = run_restartable(&mut solver, puzzle, |attempt: &Sudoku| -> Result<SolveAction, std::convert::Infallible> {
+            attempts.push(*attempt);
+            Ok(if attempts.len() == 5 { SolveAction::Restart } else { SolveAction::Continue })
+        }).unwrap();
+        assert_eq!(solution, Some(solver.run(puzzle)));
+        assert_eq!(attempts[5..10], attempts[0..5]);
+
+        // Quitting stops without a solution
+        let mut n_attempts: usize = 0;
+        let solution: Option<Sudoku> = run_restartable(&mut solver, puzzle, |_: &Sudoku| -> Result<SolveAction, std::convert::Infallible> {
+            n_attempts += 1;
+            Ok(if n_attempts == 3 { SolveAction::Quit } else { SolveAction::Continue })
+        }).unwrap();
+        assert_eq!(solution, None);
+        assert_eq!(n_attempts, 3);
+    }
+
+    #[test]
+    fn test_solve_contradictory() {
+        let mut solver: crate::solvers::BruteForceSolver = crate::solvers::BruteForceSolver::new();
+
+        // A puzzle with two 5s in the first row is rejected without ever starting the solver
+        let mut puzzle: Sudoku = load_sudoku("./tests/example.sdk").unwrap_or_else(|err| panic!("Failed to load Sudoku: {}", err.pretty())).swap_remove(0);
+        let x: usize = (0..9).find(|x| puzzle.rows[0][*x].is_none()).unwrap();
+        puzzle.rows[0][x] = puzzle.rows[0].iter().flatten().next().copied();
+        let mut n_attempts: usize = 0;
+        let outcome: SolveOutcome = run_checked(&mut solver, puzzle, |_: &Sudoku| -> Result<SolveAction, std::convert::Infallible> { n_attempts += 1; Ok(SolveAction::Continue) }).unwrap();
+        assert!(matches!(outcome, SolveOutcome::Contradictory { sudoku, .. } if sudoku == puzzle));
+        assert_eq!(n_attempts, 0);
+
+        // Well-formed puzzles are solved as usual
+        let puzzle: Sudoku = load_sudoku("./tests/example.sdk").unwrap_or_else(|err| panic!("Failed to load Sudoku: {}", err.pretty())).swap_remove(0);
+        let outcome: SolveOutcome = run_checked(&mut solver, puzzle, |_: &Sudoku| -> Result<SolveAction, std::convert::Infallible> { Ok(SolveAction::Continue) }).unwrap();
+        assert_eq!(outcome, SolveOutcome::Solved { solution: solver.run(puzzle) });
+    }
+
+    #[test]
+    fn test_steps_per_sec() {
+        assert_eq!(steps_per_sec(0, Duration::from_secs(1)), 0.0);
+        assert_eq!(steps_per_sec(100, Duration::from_secs(1)), 100.0);
+        assert_eq!(steps_per_sec(100, Duration::from_millis(500)), 200.0);
+        assert_eq!(steps_per_sec(5, Duration::from_secs(10)), 0.5);
+        // No time passed means no meaningful rate
+        assert_eq!(steps_per_sec(100, Duration::ZERO), 0.0);
+    }
+
+    #[test]
+    fn test_status_line() {
+        assert_eq!(status_line(0, 1), "Sudoku 1 of 1");
+        assert_eq!(status_line(2, 8), "Sudoku 3 of 8");
+        assert_eq!(status_line(7, 8), "Sudoku 8 of 8");
+    }
+
+    #[test]
+    fn test_dashboard() {
+        let sudokus: Vec<(String, Sudoku)> = [ "first", "second", "third", "fourth" ].into_iter().map(|name| (name.to_string(), Sudoku::empty())).collect();
+
+        // Finished ones get a mark, the current one a spinner and the rest nothing
+        assert_eq!(dashboard_items(&sudokus, &[ true, false ], Some(1)), vec![ "✓ first", "✗ second", "/ third", "  fourth" ]);
+        assert_eq!(dashboard_items(&sudokus, &[ true, false, true ], None), vec![ "✓ first", "✗ second", "✓ third", "  fourth" ]);
+
+        // The list is rendered in a pane left of the rest
+        let mut term: Terminal<ratatui::backend::TestBackend> = Terminal::new(ratatui::backend::TestBackend::new(40, 8)).unwrap();
+        term.draw(|frame| draw_dashboard(frame, dashboard_items(&sudokus, &[ true ], Some(0)), "Solving...".into())).unwrap();
+        let width: usize = term.backend().buffer().area.width as usize;
+        let lines: Vec<String> = term.backend().buffer().content.chunks(width).map(|row| row.iter().map(|cell| cell.symbol.as_str()).collect()).collect();
+        assert!(lines[0].starts_with("┌Sudokus──┐Solving..."));
+        assert!(lines[1].starts_with("│✓ first  │"));
+        assert!(lines[2].starts_with("│| second │"));
+        assert!(lines[3].starts_with("│  third  │"));
+        assert!(lines[4].starts_with("│  fourth │"));
+    }
+}
 
 
 /***** ERRORS *****/
@@ -77,24 +292,353 @@ impl error::Error for Error {
 
 
 /***** HELPER FUNCTIONS *****/
-/// Checks if 'Q' was pressed.
+/// Checks if the user pressed any key that changes the solve in progress.
 /// 
 /// # Arguments
 /// - `timeout`: The time to wait until the user presses.
 /// 
 /// # Returns
-/// True if it was, false if it wasn't.
+/// The [`SolveAction`] belonging to the pressed key, or [`SolveAction::Continue`] if nothing was pressed.
 /// 
 /// # Errors
 /// This function may error if we failed to poll for a key press.
-fn should_quit(timeout: Duration) -> Result<bool, Error> {
+fn poll_action(timeout: Duration) -> Result<SolveAction, Error> {
     // We timeout every 250 ms to make sure we keep on doing work
     if event::poll(timeout).map_err(|err| Error::KeyDetect { err })? {
         if let Event::Key(key) = event::read().map_err(|err| Error::KeyDetect { err })? {
-            return Ok(KeyCode::Char('q') == key.code);
+            return Ok(SolveAction::from_key(key.code));
+        }
+    }
+    Ok(SolveAction::Continue)
+}
+
+/// Runs a solver on a Sudoku, starting over from the initial state whenever asked to.
+/// 
+/// # Arguments
+/// - `solver`: The [`Solver`] to run.
+/// - `sudoku`: The [`Sudoku`] to solve.
+/// - `callback`: The callback to run after every step taken. Returns the [`SolveAction`] that determines how to go on, and is allowed to error.
+/// 
+/// # Returns
+/// The solved [`Sudoku`], or else the best attempt. However, if `callback` returned [`SolveAction::Quit`] at some point, `None` is returned instead.
+/// 
+/// # Errors
+/// This function errors if the `callback` errors.
+fn run_restartable<S: Solver, E>(solver: &mut S, sudoku: Sudoku, mut callback: impl FnMut(&Sudoku) -> Result<SolveAction, E>) -> Result<Option<Sudoku>, E> {
+    loop {
+        let mut restart: bool = false;
+        let solution: Option<Sudoku> = solver.run_with_callback(sudoku, |attempt: &Sudoku| -> Result<bool, E> {
+            match callback(attempt)? {
+                SolveAction::Continue => Ok(true),
+                SolveAction::Restart  => { restart = true; Ok(false) },
+                SolveAction::Quit     => Ok(false),
+            }
+        })?;
+        if !restart { return Ok(solution); }
+    }
+}
+
+/// Runs a solver on a Sudoku like [`run_restartable()`], but refuses to solve Sudokus that are contradictory to begin with.
+/// 
+/// # Arguments
+/// - `solver`: The [`Solver`] to run.
+/// - `sudoku`: The [`Sudoku`] to solve.
+/// - `callback`: The callback to run after every step taken. Returns the [`SolveAction`] that determines how to go on, and is allowed to error.
+/// 
+/// # Returns
+/// The [`SolveOutcome`] of the solve. If the Sudoku is not [well-formed](Sudoku::well_formed()), the `callback` is never called.
+/// 
+/// # Errors
+/// This function errors if the `callback` errors.
+fn run_checked<S: Solver, E>(solver: &mut S, sudoku: Sudoku, callback: impl FnMut(&Sudoku) -> Result<SolveAction, E>) -> Result<SolveOutcome, E> {
+    if let Err(reason) = sudoku.well_formed() { return Ok(SolveOutcome::Contradictory { sudoku, reason }); }
+    Ok(match run_restartable(solver, sudoku, callback)? {
+        Some(solution) => SolveOutcome::Solved { solution },
+        None           => SolveOutcome::Cancelled,
+    })
+}
+
+/// Computes the rate at which a solver is taking steps.
+/// 
+/// # Arguments
+/// - `steps`: The number of steps taken.
+/// - `elapsed`: The time it took to take them.
+/// 
+/// # Returns
+/// The number of steps per second, or `0.0` if no time has elapsed.
+fn steps_per_sec(steps: u64, elapsed: Duration) -> f64 {
+    if elapsed.is_zero() { return 0.0; }
+    steps as f64 / elapsed.as_secs_f64()
+}
+
+/// Formats the status line that shows how far through the queue of Sudokus we are.
+/// 
+/// # Arguments
+/// - `index`: The (zero-indexed) index of the current Sudoku.
+/// - `total`: The total number of Sudokus in the queue.
+/// 
+/// # Returns
+/// A line like `Sudoku 3 of 8`.
+fn status_line(index: usize, total: usize) -> String { format!("Sudoku {} of {}", index + 1, total) }
+
+/// Lists the Sudokus in a batch with their progress, for the pane next to the board.
+/// 
+/// # Arguments
+/// - `sudokus`: The `(<name>, <sudoku>)` pairs in the batch.
+/// - `finished`: Whether each of the Sudokus handled so far was solved (true) or found to be contradictory (false).
+/// - `spinner`: If given, the Sudoku after the finished ones is being solved, and this determines the frame of its spinner.
+/// 
+/// # Returns
+/// One line per Sudoku, prefixed with `✓` or `✗` if it's finished, a spinner if it's being solved or nothing if it's still pending.
+fn dashboard_items(sudokus: &[(String, Sudoku)], finished: &[bool], spinner: Option<usize>) -> Vec<String> {
+    const SPINNER: [ char; 4 ] = [ '|', '/', '-', '\\' ];
+    sudokus.iter().enumerate().map(|(i, (name, _))| {
+        let mark: char = match (finished.get(i), spinner) {
+            (Some(true), _)                           => '✓',
+            (Some(false), _)                          => '✗',
+            (None, Some(tick)) if i == finished.len() => SPINNER[tick % SPINNER.len()],
+            (None, _)                                 => ' ',
+        };
+        format!("{mark} {name}")
+    }).collect()
+}
+
+/// Draws the dashboard of a batch, i.e., a pane listing the Sudokus left of the given text.
+/// 
+/// # Arguments
+/// - `frame`: The [`Frame`] to draw on.
+/// - `items`: The lines to list in the pane (see [`dashboard_items()`]).
+/// - `text`: The text (e.g., the board) to show on the right.
+fn draw_dashboard<B: Backend>(frame: &mut Frame<B>, items: Vec<String>, text: String) {
+    // Make the pane wide enough for the longest name (plus borders), but leave the board most of the space
+    let size: Rect = frame.size();
+    let width: u16 = (items.iter().map(|item| item.chars().count()).max().unwrap_or(0) + 3).max(9).min(size.width as usize / 3) as u16;
+    let panes = Layout::default().direction(Direction::Horizontal).constraints([ Constraint::Length(width), Constraint::Min(0) ].as_ref()).split(size);
+
+    // Render the list and the text
+    let list = List::new(items.into_iter().map(ListItem::new).collect::<Vec<ListItem>>()).block(Block::default().borders(Borders::ALL).title("Sudokus"));
+    frame.render_widget(list, panes[0]);
+    frame.render_widget(Paragraph::new(text), panes[1]);
+}
+
+/// Computes how much longer a solution should be shown before auto-advancing to the next one.
+/// 
+/// # Arguments
+/// - `elapsed`: The time the solution has been shown so far.
+/// - `pause`: The total time to show the solution.
+/// 
+/// # Returns
+/// The remaining time to wait, or [`None`] if it's time to advance.
+fn remaining_pause(elapsed: Duration, pause: Duration) -> Option<Duration> {
+    let remaining: Duration = pause.saturating_sub(elapsed);
+    if remaining.is_zero() { None } else { Some(remaining) }
+}
+
+
+
+
+
+/***** AUXILLARY *****/
+/// Defines what the user wants to do with a solve in progress (see [`Engine::solve()`]).
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum SolveAction {
+    /// Keep on solving.
+    Continue,
+    /// Start solving the current Sudoku over from its initial state.
+    Restart,
+    /// Stop solving altogether.
+    Quit,
+}
+impl SolveAction {
+    /// Determines the action belonging to a key press.
+    /// 
+    /// The keybindings are:
+    /// - `r`: [Restart](SolveAction::Restart) the current Sudoku; and
+    /// - `q`: [Quit](SolveAction::Quit).
+    /// 
+    /// Any other key [continues](SolveAction::Continue).
+    /// 
+    /// # Arguments
+    /// - `code`: The [`KeyCode`] of the pressed key.
+    /// 
+    /// # Returns
+    /// The matching SolveAction.
+    #[inline]
+    pub fn from_key(code: KeyCode) -> Self {
+        match code {
+            KeyCode::Char('r') => Self::Restart,
+            KeyCode::Char('q') => Self::Quit,
+            _                  => Self::Continue,
+        }
+    }
+}
+
+/// Defines how solving a single Sudoku in the UI ended (see [`Engine::solve()`]).
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SolveOutcome {
+    /// The solver ran to completion.
+    Solved { solution: Sudoku },
+    /// The Sudoku was contradictory to begin with, so there was nothing to solve. It is returned untouched.
+    Contradictory { sudoku: Sudoku, reason: InvalidReason },
+    /// The user quit halfway through.
+    Cancelled,
+}
+
+
+
+/// Defines the state of the interactive assist mode (see [`Engine::assist()`]).
+#[derive(Clone, Debug)]
+pub struct AssistState {
+    /// The Sudoku as the user sees it.
+    pub sudoku          : Sudoku,
+    /// The (precomputed) solution to the Sudoku.
+    pub solution        : Sudoku,
+    /// The position of the cursor, as an `(x, y)` pair.
+    pub cursor          : (usize, usize),
+    /// Whether to show the candidates of the cell under the cursor (and the pencil marks of all cells).
+    pub show_candidates : bool,
+    /// If given, cells with more candidates than this are shown blank in the pencil marks (see [`SudokuCandidateFormatter::max_candidates()`](crate::sudoku::SudokuCandidateFormatter::max_candidates())).
+    pub max_candidates  : Option<usize>,
+    /// If given, the user is typing the filename to save the Sudoku to.
+    pub prompt          : Option<String>,
+    /// If given, a message about the last save to show to the user.
+    pub status          : Option<String>,
+}
+impl AssistState {
+    /// Constructor for the AssistState.
+    /// 
+    /// # Arguments
+    /// - `sudoku`: The [`Sudoku`] to assist with.
+    /// - `solution`: The solution to the `sudoku`, from which cells are revealed.
+    /// 
+    /// # Returns
+    /// A new instance of Self with the cursor in the top-left corner.
+    #[inline]
+    pub fn new(sudoku: Sudoku, solution: Sudoku) -> Self {
+        Self { sudoku, solution, cursor: (0, 0), show_candidates: false, max_candidates: None, prompt: None, status: None }
+    }
+
+
+
+    /// Saves the Sudoku as the user sees it to a file.
+    /// 
+    /// The format is chosen by the [extension](FileType::from_ext()) of the file, and written with [`save_sudoku_of_type()`].
+    /// 
+    /// # Arguments
+    /// - `path`: The path of the file to write to. Will be overwritten if it already exists.
+    /// 
+    /// # Errors
+    /// This function errors if the extension is missing, unknown or of a type that cannot be written, or if we failed to create or write to the file.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), SaveError> {
+        let path: &Path = path.as_ref();
+        let ftype: FileType = match path.extension() {
+            Some(ext) => FileType::from_ext(ext).ok_or_else(|| SaveError::UnknownExtension { path: path.into(), ext: ext.into() })?,
+            None      => { return Err(SaveError::NoExtension { path: path.into() }); },
+        };
+        save_sudoku_of_type(&self.sudoku, path, ftype)
+    }
+
+    /// Updates the state according to the given key press.
+    /// 
+    /// The arrow keys move the cursor, `h` reveals the solution of the cell under the cursor, `c` toggles showing its candidates (and the pencil marks of the whole Sudoku), `s` prompts for a filename to [save](AssistState::save()) to and `q` quits.
+    /// 
+    /// While prompting, keys edit the filename instead. `Enter` saves and `Esc` cancels.
+    /// 
+    /// # Arguments
+    /// - `key`: The [`KeyCode`] of the key that was pressed.
+    /// 
+    /// # Returns
+    /// False if the user wants to quit, or true otherwise.
+    pub fn handle_key(&mut self, key: KeyCode) -> bool {
+        // Edit the filename if we're prompting for one
+        if let Some(prompt) = &mut self.prompt {
+            match key {
+                KeyCode::Char(c)   => { prompt.push(c); },
+                KeyCode::Backspace => { prompt.pop(); },
+                KeyCode::Esc       => { self.prompt = None; },
+                KeyCode::Enter     => {
+                    let path: String = self.prompt.take().unwrap();
+                    self.status = Some(match self.save(&path) {
+                        Ok(_)    => format!("Saved to '{path}'"),
+                        Err(err) => format!("Failed to save to '{path}': {err}"),
+                    });
+                },
+                _ => {},
+            }
+            return true;
+        }
+
+        let (x, y): (usize, usize) = self.cursor;
+        match key {
+            KeyCode::Left  => { self.cursor.0 = x.saturating_sub(1); },
+            KeyCode::Right => { self.cursor.0 = (x + 1).min(8); },
+            KeyCode::Up    => { self.cursor.1 = y.saturating_sub(1); },
+            KeyCode::Down  => { self.cursor.1 = (y + 1).min(8); },
+
+            KeyCode::Char('h') if self.sudoku.rows[y][x].is_none() => { self.sudoku.rows[y][x] = self.solution.rows[y][x]; },
+            KeyCode::Char('c') => { self.show_candidates = !self.show_candidates; },
+            KeyCode::Char('s') => { self.prompt = Some(String::new()); self.status = None; },
+            KeyCode::Char('q') => { return false; },
+
+            _ => {},
+        }
+        true
+    }
+
+    /// Renders the current state as text.
+    /// 
+    /// # Returns
+    /// A [`String`] with the Sudoku, where the cell under the cursor is surrounded by brackets. If candidates are shown, it is instead rendered with the pencil marks of every cell (limited by `max_candidates`), followed by the cursor cell's candidates.
+    pub fn render(&self) -> String {
+        // Show the pencil marks instead of the values if told to do so
+        if self.show_candidates {
+            let mut marks: SudokuCandidateFormatter = self.sudoku.pencil_marks();
+            if let Some(max) = self.max_candidates { marks.max_candidates(max); }
+            let (x, y): (usize, usize) = self.cursor;
+            let mut text: String = format!("{marks}\nCandidates of cell ({},{}): {:?}\n", x + 1, y + 1, self.sudoku.candidates(x, y));
+            self.render_prompt(&mut text);
+            return text;
+        }
+
+        let mut text: String = String::new();
+        for y in 0..9 {
+            // Generate the top thing if needed
+            if y == 0 { text.push_str("┌───┬───┬───╥───┬───┬───╥───┬───┬───┐\n"); }
+
+            // Print the values in this row
+            text.push('│');
+            for x in 0..9 {
+                let value: String = self.sudoku.rows[y][x].map(|v| format!("{v}")).unwrap_or(" ".into());
+                if (x, y) == self.cursor { text.push_str(&format!("[{value}]")); } else { text.push_str(&format!(" {value} ")); }
+                text.push(if x < 8 && x % 3 == 2 { '║' } else { '│' });
+            }
+            text.push('\n');
+
+            // Print the bottom thing
+            if y < 8 && y % 3 == 2 {
+                text.push_str("╞═══╪═══╪═══╬═══╪═══╪═══╬═══╪═══╪═══╡\n");
+            } else if y < 8 {
+                text.push_str("├───┼───┼───╫───┼───┼───╫───┼───┼───┤\n");
+            } else {
+                text.push_str("└───┴───┴───╨───┴───┴───╨───┴───┴───┘\n");
+            }
+        }
+
+        self.render_prompt(&mut text);
+        text
+    }
+
+    /// Adds the save prompt or the result of the last save (if any) to a render.
+    /// 
+    /// # Arguments
+    /// - `text`: The render to add it to.
+    fn render_prompt(&self, text: &mut String) {
+        if let Some(prompt) = &self.prompt {
+            text.push_str(&format!("\nSave as (press 'Enter' to save or 'Esc' to cancel): {prompt}_\n"));
+        } else if let Some(status) = &self.status {
+            text.push_str(&format!("\n{status}\n"));
         }
     }
-    Ok(false)
 }
 
 
@@ -105,9 +649,13 @@ fn should_quit(timeout: Duration) -> Result<bool, Error> {
 /// Our own wrapper around ratatui's [Terminal](RTerminal) that automatically restores the terminal when it goes out-of-scope.
 pub struct Engine<S> {
     /// The solver to run Sudoku's with.
-    solver  : S,
+    solver         : S,
     /// The time to wait in between compute steps.
-    timeout : Duration,
+    timeout        : Duration,
+    /// If given, the time to show a solution before automatically moving on to the next Sudoku. Otherwise, waits for a key press.
+    auto_advance   : Option<Duration>,
+    /// If given, the maximum number of candidates a cell may have to show them in the assist mode's pencil marks.
+    max_candidates : Option<usize>,
 
     /// The nested ratatui's terminal
     term : Terminal<CrosstermBackend<Stdout>>,
@@ -149,12 +697,40 @@ impl<S> Engine<S> {
 
         // We can finally construct ourselves!
         Ok(Self {
-            timeout : step_time,
+            timeout        : step_time,
+            auto_advance   : None,
+            max_candidates : None,
             solver,
 
             term,
         })
     }
+
+    /// Makes the Engine automatically move on to the next Sudoku after showing a solution for some time, instead of waiting for a key press.
+    /// 
+    /// # Arguments
+    /// - `pause`: The time to show every solution.
+    /// 
+    /// # Returns
+    /// Self for chaining.
+    #[inline]
+    pub fn with_auto_advance(mut self, pause: Duration) -> Self {
+        self.auto_advance = Some(pause);
+        self
+    }
+
+    /// Limits which cells show their pencil marks in the assist mode, to keep the board readable when most cells still have many candidates.
+    /// 
+    /// # Arguments
+    /// - `max`: The maximum number of candidates a cell may have to show them. Cells with more are shown blank.
+    /// 
+    /// # Returns
+    /// Self for chaining.
+    #[inline]
+    pub fn with_max_candidates(mut self, max: usize) -> Self {
+        self.max_candidates = Some(max);
+        self
+    }
 }
 impl<S> Drop for Engine<S> {
     fn drop(&mut self) {
@@ -181,31 +757,117 @@ impl<S: Solver> Engine<S> {
 
         // The game loop, as it were
         let mut solutions: Vec<Sudoku> = Vec::with_capacity(sudokus.len());
-        for (name, sudoku) in sudokus {
+        let mut finished: Vec<bool> = Vec::with_capacity(sudokus.len());
+        for (i, (name, sudoku)) in sudokus.iter().enumerate() {
+            let status: String = status_line(i, sudokus.len());
+
             // Run the solver, updating the UI at the end of every run
-            let solution: Option<Sudoku> = self.solver.run_with_callback(*sudoku, |sudoku: &Sudoku| -> Result<bool, Error> {
+            let mut start: Instant = Instant::now();
+            let mut steps: u64 = 0;
+            let outcome: SolveOutcome = run_checked(&mut self.solver, *sudoku, |sudoku: &Sudoku| -> Result<SolveAction, Error> {
+                // Update the statistics
+                steps += 1;
+                let rate: f64 = steps_per_sec(steps, start.elapsed());
+
                 // Draw the current state
                 if let Err(err) = self.term.draw(|frame: &mut Frame<CrosstermBackend<Stdout>>| {
-                    let title = Paragraph::new(format!("Solving sudoku '{name}'...\n(Press 'Q' to cancel)\n\n{sudoku}"));
-                    frame.render_widget(title, frame.size());
+                    let items: Vec<String> = dashboard_items(sudokus, &finished, Some(steps as usize));
+                    draw_dashboard(frame, items, format!("{status}\nSolving sudoku '{name}'...\n(Press 'R' to restart or 'Q' to cancel)\n\n{sudoku}\nSteps: {steps} ({rate:.1} steps/s)"));
                     // frame.render_widget(title, Rect { x: 0, y: 0, width: frame.size().width, height: frame.size().height / 8 });
                     // frame.render_widget(sudoku.render(), Rect { x: 0, y: frame.size().height / 8, width: frame.size().width, height: frame.size().height - frame.size().height / 8 });
                 }) {
                     return Err(Error::FrameDraw { err });
                 };
     
-                // Check for key presses
-                Ok(!should_quit(self.timeout)?)
+                // Check for key presses, resetting the statistics if we start over
+                let action: SolveAction = poll_action(self.timeout)?;
+                if action == SolveAction::Restart {
+                    start = Instant::now();
+                    steps = 0;
+                }
+                Ok(action)
             })?;
 
-            // Add it if we have any, else quit
-            match solution {
-                Some(solution) => { solutions.push(solution); },
-                None           => { return Ok(solutions); },
+            // Add it if we have any (leaving contradictory ones untouched), else quit
+            let (header, solution): (String, Sudoku) = match outcome {
+                SolveOutcome::Solved { solution }              => { finished.push(true); (format!("Solved sudoku '{name}'"), solution) },
+                SolveOutcome::Contradictory { sudoku, reason } => { finished.push(false); (format!("Sudoku '{name}' is contradictory and cannot be solved: {reason}"), sudoku) },
+                SolveOutcome::Cancelled                        => { return Ok(solutions); },
+            };
+            solutions.push(solution);
+
+            // Show the result until it's time to advance
+            let shown: Instant = Instant::now();
+            loop {
+                // Determine how long to wait for
+                let remaining: Option<Duration> = match self.auto_advance {
+                    Some(pause) => match remaining_pause(shown.elapsed(), pause) {
+                        Some(remaining) => Some(remaining),
+                        None            => { break; },
+                    },
+                    None => None,
+                };
+
+                // Draw the solution
+                if let Err(err) = self.term.draw(|frame: &mut Frame<CrosstermBackend<Stdout>>| {
+                    let advance: String = match remaining {
+                        Some(remaining) => format!("Continuing in {:.1}s (press any key to continue now, or 'Q' to quit)", remaining.as_secs_f64()),
+                        None            => "(Press any key to continue, or 'Q' to quit)".into(),
+                    };
+                    draw_dashboard(frame, dashboard_items(sudokus, &finished, None), format!("{status}\n{header}\n{advance}\n\n{solution}"));
+                }) {
+                    return Err(Error::FrameDraw { err });
+                }
+
+                // Wait for a key press, updating the countdown every now and then
+                if let Some(remaining) = remaining {
+                    if !event::poll(remaining.min(Duration::from_millis(100))).map_err(|err| Error::KeyDetect { err })? { continue; }
+                }
+                if let Event::Key(key) = event::read().map_err(|err| Error::KeyDetect { err })? {
+                    if key.code == KeyCode::Char('q') { return Ok(solutions); }
+                    break;
+                }
             }
         }
 
         // Done!
         Ok(solutions)
     }
+
+
+    /// Runs an interactive assist mode for a Sudoku, where the user can move a cursor around and reveal the solution of individual cells.
+    /// 
+    /// See [`AssistState::handle_key()`] for the keybindings.
+    /// 
+    /// # Arguments
+    /// - `name`: The name of the Sudoku to show.
+    /// - `sudoku`: The [`Sudoku`] to assist with.
+    /// 
+    /// # Returns
+    /// The Sudoku as the user left it when quitting.
+    /// 
+    /// # Errors
+    /// This function may error if there was some error while running.
+    pub fn assist(&mut self, name: &str, sudoku: Sudoku) -> Result<Sudoku, Error> {
+        // Precompute the solution
+        let solution: Sudoku = self.solver.run(sudoku);
+
+        // Run the input loop
+        let mut state: AssistState = AssistState::new(sudoku, solution);
+        state.max_candidates = self.max_candidates;
+        loop {
+            // Draw the current state
+            if let Err(err) = self.term.draw(|frame: &mut Frame<CrosstermBackend<Stdout>>| {
+                let text = Paragraph::new(format!("Assisting with sudoku '{name}'...\n(Use the arrow keys to move, 'H' to reveal a cell, 'C' to show its candidates, 'S' to save and 'Q' to quit)\n\n{}", state.render()));
+                frame.render_widget(text, frame.size());
+            }) {
+                return Err(Error::FrameDraw { err });
+            }
+
+            // Wait for the next key press
+            if let Event::Key(key) = event::read().map_err(|err| Error::KeyDetect { err })? {
+                if !state.handle_key(key.code) { return Ok(state.sudoku); }
+            }
+        }
+    }
 }
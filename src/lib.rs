@@ -4,7 +4,7 @@
 //  Created:
 //    10 Aug 2023, 23:02:19
 //  Last edited:
-//    11 Aug 2023, 11:42:49
+//    16 Oct 2026, 11:11:02
 //  Auto updated?
 //    Yes
 // 
@@ -12,11 +12,19 @@
 //!   A sudoku solver in Rust, using
 //!   [ratatui](https://github.com/ratatui-org/ratatui) for a fancy
 //!   interface.
+//!   
+//!   Without the (default) `std` feature, only the `alloc`-based core
+//!   (i.e., the Sudoku model and solvers) is available.
 // 
 
+#![cfg_attr(not(feature = "std"), no_std)]
+extern crate alloc;
+
 // Declare the modules
+#[cfg(feature = "std")]
 pub mod engine;
 pub mod solvers;
 pub mod spec;
 pub mod sudoku;
+#[cfg(feature = "std")]
 pub mod utils;
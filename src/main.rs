@@ -4,7 +4,7 @@
 //  Created:
 //    10 Aug 2023, 23:01:37
 //  Last edited:
-//    12 Aug 2023, 00:04:51
+//    16 Oct 2026, 12:42:58
 //  Auto updated?
 //    Yes
 // 
@@ -18,13 +18,13 @@ use std::time::{Duration, Instant};
 use clap::Parser;
 use console::Style;
 use humanlog::{DebugMode, HumanLogger};
-use log::{error, warn};
+use log::{error, info, warn};
 
 use sudoku_solver::engine::Engine;
-use sudoku_solver::solvers::{BruteForceSolver, Solver as _};
-use sudoku_solver::spec::FileType;
+use sudoku_solver::solvers::{BruteForceSolver, SolutionCache, SolveStats, Solver as _};
+use sudoku_solver::spec::{Colour, FileType, Uniqueness};
 use sudoku_solver::sudoku::Sudoku;
-use sudoku_solver::utils::{load_sudoku, load_sudoku_of_type, PrettyError as _};
+use sudoku_solver::utils::{converted_path, count_report, load_sudoku_max, load_sudoku_of_type_max, save_sudoku_of_type, solution_header, split_sudokus, write_compact_solutions, PrettyError as _};
 
 
 /***** ARGUMENTS *****/
@@ -33,25 +33,71 @@ use sudoku_solver::utils::{load_sudoku, load_sudoku_of_type, PrettyError as _};
 #[clap(name = "sudoku_solver", about = "A solver for Sudoku's.")]
 struct Arguments {
     /// Whether to load from a file or not.
-    #[clap(name="FILES", help="If given, loads the Sudoku from the given file instead of querying the user. Check '--file-type' to change the default file type.")]
+    #[clap(name="FILES", help="If given, loads the Sudoku from the given file instead of querying the user. If compiled with the 'net' feature, this may also be an http(s) URL. Check '--file-type' to change the default file type.")]
     files : Vec<PathBuf>,
 
     /// If given, does not show the final version but instead shows only the solutions to the `n` first cells.
-    #[clap(long, help="If given, does not show the final version but instead shows only the solutions to the given number of first empty cells.")]
-    hint     : Option<u8>,
+    #[clap(long, value_parser=clap::value_parser!(u8).range(1..), help="If given, does not show the final version but instead shows only the solutions to the given number of first empty cells. Must be at least 1.")]
+    hint           : Option<u8>,
+    /// Runs the interactive assist mode instead of the solver.
+    #[clap(long, conflicts_with="headless", help="If given, does not solve the Sudokus but instead lets you move a cursor around and reveal the solution of individual cells.")]
+    assist         : bool,
+    /// If given, limits which cells show their pencil marks in the assist mode.
+    #[clap(long, requires="assist", help="If given, only shows the pencil marks of cells with at most the given number of candidates in the assist mode, to keep the board readable early on. Only works with '--assist'.")]
+    max_candidates : Option<usize>,
     /// Runs the solver without UI. Note that you cannot select files this way.
     #[clap(long, help="If given, runs without UI at maximum speed. Note that you cannot insert a Sudoku yourself this way.")]
-    headless : bool,
+    headless       : bool,
+
+    /// If given, prints statistics about the search tree of every Sudoku.
+    #[clap(long, requires="headless", help="If given, prints statistics about the search of every Sudoku (nodes expanded, maximum depth, backtracks and wall time). Only works with '--headless'.")]
+    profile  : bool,
+    /// If given, prints only the compact solutions.
+    #[clap(long, requires="headless", conflicts_with_all=[ "hint", "side_by_side", "profile", "count_only", "check_unique", "verify_input", "split", "convert" ], help="If given, prints every solution as a single line of 81 cells and nothing else, e.g., for piping into other tools. Only works with '--headless'.")]
+    compact  : bool,
+    /// If given, solves identical Sudokus only once.
+    #[clap(long, requires="headless", conflicts_with="profile", help="If given, caches the solutions of the Sudokus such that identical puzzles (e.g., across files) are only solved once. Only works with '--headless'.")]
+    cache    : bool,
 
     /// If given, verifies that the input Sudoku is well-formed.
     #[clap(short='V', long, help="If given, verifies that the input Sudoku is well-formed.")]
     verify_input : bool,
+    /// If given, only counts the loaded Sudokus instead of solving them.
+    #[clap(long, conflicts_with_all=[ "assist", "hint" ], help="If given, only loads the input files and prints the number of Sudokus in each of them (and in total) without solving anything.")]
+    count_only   : bool,
+    /// If given, writes every loaded Sudoku to its own file in this directory instead of solving them.
+    #[clap(long, conflicts_with_all=[ "assist", "hint", "count_only" ], help="If given, does not solve the Sudokus but instead writes every loaded puzzle to its own file in the given directory (created if needed). See '--output-type' to choose the format.")]
+    split        : Option<PathBuf>,
+    /// If given, converts every input file to `--output-type` instead of solving them.
+    #[clap(long, conflicts_with_all=[ "assist", "hint", "count_only", "split", "check_unique" ], help="If given, does not solve the Sudokus but instead writes every input file next to itself in the type given by '--output-type' (e.g., 'puzzle.sdk' becomes 'puzzle.json'). Files with multiple Sudokus are written as one file per Sudoku.")]
+    convert      : bool,
+    /// Determines the type of the files written by `--split` and `--convert`.
+    #[clap(short='o', long, alias="output-format", default_value="sdk", help="The type of the files written by '--split' or '--convert'. Can be 'json', 'sdk', 'txt', 'ss' or 'ss_new'.")]
+    output_type  : FileType,
+    /// If given, only checks whether the loaded Sudokus have a unique solution.
+    #[clap(long, conflicts_with_all=[ "assist", "hint", "count_only", "split" ], help="If given, does not solve the Sudokus but instead reports whether each of them has no, a unique or multiple solutions. Stops searching as soon as a second solution is found.")]
+    check_unique : bool,
+    /// If given, stops loading Sudokus after this many.
+    #[clap(long, help="If given, stops loading Sudokus after the given number of puzzles have been loaded (counting over all input files).")]
+    max_puzzles  : Option<usize>,
     /// Determines the type of the loaded file.
     #[clap(short='t', long, help="Overrides deriving the input file type with this fixed type instead. Note that this applies to ALL input files. Will be ignored if no file is given.")]
     input_type   : Option<FileType>,
     /// Determines the timout in between steps (in ms).
     #[clap(short='T', long, default_value="50", help="The timeout in between compute steps, for visualisation purposes.")]
     timeout      : u64,
+    /// If given, shows the solutions next to the puzzles instead of on top of them.
+    #[clap(long, conflicts_with="hint", help="If given, prints every puzzle and its solution next to each other instead of highlighting the givens in the solution.")]
+    side_by_side : bool,
+    /// If given, overrides the colour of the givens in the final output.
+    #[clap(long, help="If given, prints the givens in the final output with this colour instead of blue. Can be 'black', 'red', 'green', 'yellow', 'blue', 'magenta', 'cyan' or 'white'.")]
+    given_color  : Option<Colour>,
+    /// If given, overrides the colour of the solved cells in the final output.
+    #[clap(long, help="If given, prints the solved cells in the final output with this colour instead of the default. Can be 'black', 'red', 'green', 'yellow', 'blue', 'magenta', 'cyan' or 'white'.")]
+    solved_color : Option<Colour>,
+    /// If given, automatically moves on to the next Sudoku in the UI after this many milliseconds.
+    #[clap(long, conflicts_with="headless", help="If given, shows every solution in the UI for the given number of milliseconds before automatically moving on to the next Sudoku, instead of waiting for a key press.")]
+    auto_advance : Option<u64>,
 }
 
 
@@ -71,15 +117,21 @@ fn main() {
     // Load the Sudokus, if any
     let mut sudokus: Vec<(String, Sudoku)> = Vec::with_capacity(args.files.len());
     for sudoku_path in args.files {
-        // Attempt to load it according to our method
-        println!("Loading Sudoku '{}'...", sudoku_path.display());
+        // Stop if we've already loaded enough
+        if let Some(max_puzzles) = args.max_puzzles {
+            if sudokus.len() >= max_puzzles { break; }
+        }
+
+        // Attempt to load it according to our method (but only as many as we're still allowed to)
+        if !args.compact { println!("Loading Sudoku '{}'...", sudoku_path.display()); }
+        let max: Option<usize> = args.max_puzzles.map(|max_puzzles| max_puzzles - sudokus.len());
         let mut fsudokus: Vec<Sudoku> = if let Some(ftype) = args.input_type {
-            match load_sudoku_of_type(&sudoku_path, ftype) {
+            match load_sudoku_of_type_max(&sudoku_path, ftype, max) {
                 Ok(sudoku) => sudoku,
                 Err(err)   => { error!("Failed to load sudoku file '{}' as {}: {}", sudoku_path.display(), ftype, err); std::process::exit(1); },
             }
         } else {
-            match load_sudoku(&sudoku_path) {
+            match load_sudoku_max(&sudoku_path, max) {
                 Ok(sudoku) => sudoku,
                 Err(err)   => { error!("Failed to load sudoku file '{}': {}", sudoku_path.display(), err); std::process::exit(1); },
             }
@@ -98,6 +150,9 @@ fn main() {
             for (name, sudoku) in &to_be_added {
                 if let Err(reason) = sudoku.well_formed() {
                     error!("Sudoku '{name}' is ill-formed: {reason}");
+                    if let Some((x, y)) = sudoku.suggest_repair() {
+                        info!("Clearing cell ({},{}) of Sudoku '{name}' would repair it", x + 1, y + 1);
+                    }
                     println!("{}", sudoku.coloured());
                     errored = true;
                 }
@@ -105,10 +160,55 @@ fn main() {
             if errored { std::process::exit(0); }
         }
 
+        // Convert them if that's all we have to do
+        if args.convert {
+            for (i, (name, sudoku)) in to_be_added.iter().enumerate() {
+                let path: PathBuf = converted_path(&sudoku_path, if to_be_added.len() > 1 { Some(i) } else { None }, args.output_type);
+                if path == sudoku_path { error!("Converting '{}' to {} would overwrite it", sudoku_path.display(), args.output_type); std::process::exit(1); }
+                if let Err(err) = save_sudoku_of_type(sudoku, &path, args.output_type) { error!("Failed to convert Sudoku '{name}': {}", err.pretty()); std::process::exit(1); }
+                println!("Converted Sudoku '{name}' to '{}'", path.display());
+            }
+        }
+
+        // Report the number of Sudokus if that's all we have to do
+        if args.count_only {
+            let boards: Vec<Sudoku> = to_be_added.iter().map(|(_, sudoku)| *sudoku).collect();
+            println!("{}", count_report(&sudoku_path, &boards));
+        }
+
         // Add it to the list
         sudokus.extend(to_be_added);
     }
-    println!();
+    if !args.compact { println!(); }
+
+    // Stop if we only had to count them
+    if args.count_only {
+        println!("Loaded {} Sudoku(s) in total", sudokus.len());
+        return;
+    }
+
+    // Stop if we only had to convert them
+    if args.convert { return; }
+
+    // Check uniqueness if that's all we have to do
+    if args.check_unique {
+        let mut solver: BruteForceSolver = BruteForceSolver::new();
+        for (name, sudoku) in &sudokus {
+            let uniqueness: Uniqueness = solver.uniqueness(*sudoku);
+            println!("Sudoku '{name}': {uniqueness}");
+        }
+        return;
+    }
+
+    // Write them to separate files if that's all we have to do
+    if let Some(dir) = &args.split {
+        let boards: Vec<Sudoku> = sudokus.iter().map(|(_, sudoku)| *sudoku).collect();
+        match split_sudokus(&boards, dir, args.output_type) {
+            Ok(paths) => println!("Wrote {} Sudoku(s) to '{}'", paths.len(), dir.display()),
+            Err(err)  => { error!("Failed to split Sudokus: {}", err.pretty()); std::process::exit(1); },
+        }
+        return;
+    }
 
     // Now either run with UI or without.
     if !args.headless {
@@ -119,10 +219,23 @@ fn main() {
             Ok(ui)   => ui,
             Err(err) => { error!("{}", err.pretty()); std::process::exit(1); },
         };
+        if let Some(pause) = args.auto_advance { ui = ui.with_auto_advance(Duration::from_millis(pause)); }
+        if let Some(max) = args.max_candidates { ui = ui.with_max_candidates(max); }
 
         // Query for sudoku's if not given
         /* TODO */
 
+        // Run the assist mode if told to do so
+        if args.assist {
+            for (name, sudoku) in &sudokus {
+                if let Err(err) = ui.assist(name, *sudoku) {
+                    error!("Failed to assist with Sudoku '{name}': {}", err.pretty());
+                    std::process::exit(1);
+                }
+            }
+            return;
+        }
+
         // Run the program
         let solutions: Vec<Sudoku> = match ui.solve(&sudokus) {
             Ok(sudokus) => sudokus,
@@ -145,38 +258,54 @@ fn main() {
 
         // Start the solver
         let mut solver: BruteForceSolver = BruteForceSolver::new();
+        let mut cache: Option<SolutionCache> = if args.cache { Some(SolutionCache::new()) } else { None };
         let solutions: Vec<Sudoku> = sudokus.iter().map(|s| {
-            println!("Solving Sudoku '{}'...", s.0);
+            if !args.compact { println!("Solving Sudoku '{}'...", s.0); }
+
+            // Solve it, re-using the solution of an identical puzzle if we have it
             let start: Instant = Instant::now();
-            let solution: Sudoku = solver.run(s.1);
-            println!("(Time taken: {}ms)", start.elapsed().as_millis());
+            let solution: Sudoku = if args.profile {
+                let (solution, stats): (Sudoku, SolveStats) = solver.run_with_stats(s.1);
+                print!("{stats}");
+                return solution;
+            } else if let Some(cache) = &mut cache {
+                let n_cached: usize = cache.len();
+                let solution: Sudoku = cache.solve(&mut solver, s.1);
+                if cache.len() == n_cached {
+                    if !args.compact { println!("(Identical to an earlier Sudoku; re-using its solution)"); }
+                    return solution;
+                }
+                solution
+            } else {
+                solver.run(s.1)
+            };
+            if !args.compact { println!("(Time taken: {}ms)", start.elapsed().as_millis()); }
             solution
         }).collect();
-        println!();
+        if !args.compact { println!(); }
     
+        // Determine the colours to write with
+        let given  : Style         = args.given_color.map(|c| Style::new().bold().fg(c.into())).unwrap_or_else(|| Style::new().bold().blue());
+        let solved : Option<Style> = args.solved_color.map(|c| Style::new().bold().fg(c.into()));
+
         // Write it to the terminal
-        if let Some(n_hints) = args.hint {
+        if args.compact {
+            if let Err(err) = write_compact_solutions(&solutions, std::io::stdout().lock()) {
+                error!("Failed to write solutions: {err}");
+                std::process::exit(1);
+            }
+        } else if let Some(n_hints) = args.hint {
             for (i, solution) in solutions.into_iter().enumerate() {
                 println!("Hint to Sudoku '{}':", sudokus[i].0);
 
-                // Find the first N slots in the mask and add the solutions from the solved sudoku
-                let mut hint: Sudoku = sudokus[i].1;
-                let mut j: usize = 0;
-                'main: for y in 0..9 {
-                    for x in 0..9 {
-                        // Quit if we exceeded the number of requested hints
-                        if j >= n_hints as usize { break 'main; }
-
-                        // If the hint is empty, add in the thing
-                        if hint.rows[y][x].is_none() {
-                            hint.rows[y][x] = solution.rows[y][x];
-                            j += 1;
-                        }
-                    }
+                // Fill in the first N empty cells from the solved sudoku, warning if we cannot give as many hints as requested
+                let (hint, n_given): (Sudoku, usize) = sudokus[i].1.with_hints(&solution, n_hints as usize);
+                if n_given < n_hints as usize {
+                    warn!("Requested {} hints, but Sudoku '{}' only has {} empty cells; showing hints for all of them", n_hints, sudokus[i].0, n_given);
                 }
 
                 // Show the hint
-                print!("{}", hint.masked(&sudokus[i].1).colour(Style::new().green().bold()));
+                print!("{}", hint.masked(&sudokus[i].1).given(given.clone()).colour(solved.clone().unwrap_or_else(|| Style::new().green().bold())));
 
                 // Show a warning if incomplete still
                 if !hint.is_finished() {
@@ -186,8 +315,12 @@ fn main() {
             }
         } else {
             for (i, solution) in solutions.into_iter().enumerate() {
-                println!("Solution to Sudoku '{}':", sudokus[i].0);
-                println!("{}", solution.masked(&sudokus[i].1));
+                println!("{}", solution_header(&sudokus[i].0, &sudokus[i].1));
+                if args.side_by_side {
+                    println!("{}", sudokus[i].1.side_by_side(&solution));
+                } else {
+                    println!("{}", solution.masked(&sudokus[i].1).given(given.clone()).colour(solved.clone().unwrap_or_else(|| Style::new().bold())));
+                }
             }
         }
     }
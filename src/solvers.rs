@@ -4,7 +4,7 @@
 //  Created:
 //    10 Aug 2023, 23:03:23
 //  Last edited:
-//    11 Aug 2023, 17:01:28
+//    16 Oct 2026, 12:42:16
 //  Auto updated?
 //    Yes
 // 
@@ -12,7 +12,24 @@
 //!   Implements the Sudoku solver(s).
 // 
 
-use crate::sudoku::Sudoku;
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt::{Display, Formatter, Result as FResult};
+use core::time::Duration;
+#[cfg(feature = "std")]
+use std::time::Instant;
+
+#[cfg(feature = "std")]
+use rand::{Rng, SeedableRng as _};
+#[cfg(feature = "std")]
+use rand::rngs::StdRng;
+#[cfg(feature = "std")]
+use rand::seq::SliceRandom as _;
+
+use crate::spec::Uniqueness;
+use crate::sudoku::{CandidateGrid, Sudoku};
 
 
 /***** TESTS *****/
@@ -21,6 +38,51 @@ mod tests {
     use crate::utils::{load_sudoku, PrettyError as _};
     use super::*;
 
+    /// Generates a random puzzle with at least one solution, by shuffling a known solution and then emptying random cells.
+    fn random_puzzle(rng: &mut StdRng) -> Sudoku {
+        let solution: Sudoku = load_sudoku("./tests/correct.json").unwrap_or_else(|err| panic!("Failed to load Sudoku: {}", err.pretty())).swap_remove(0);
+
+        // Relabel the digits, permute the rows and columns (keeping bands and stacks intact) and maybe transpose
+        let mut digits: Vec<u8> = (1..=9).collect();
+        digits.shuffle(rng);
+        let perm = |rng: &mut StdRng| -> Vec<usize> {
+            let mut bands: Vec<usize> = vec![ 0, 1, 2 ];
+            bands.shuffle(rng);
+            bands.into_iter().flat_map(|b| { let mut rows: Vec<usize> = vec![ 3 * b, 3 * b + 1, 3 * b + 2 ]; rows.shuffle(rng); rows }).collect()
+        };
+        let (rows, cols): (Vec<usize>, Vec<usize>) = (perm(rng), perm(rng));
+        let transpose: bool = rng.gen_bool(0.5);
+
+        // Keep about half of the cells
+        Sudoku::from_fn(|x, y| {
+            let (x, y): (usize, usize) = if transpose { (y, x) } else { (x, y) };
+            if rng.gen_bool(0.5) { solution.rows[rows[y]][cols[x]].map(|v| digits[v as usize - 1]) } else { None }
+        })
+    }
+
+    /// Checks the invariants that any solver should uphold on the given puzzle.
+    fn check_invariants<S: Solver>(mut new_solver: impl FnMut() -> S, puzzle: Sudoku) {
+        let solution: Sudoku = new_solver().run(puzzle);
+        if !solution.is_finished() { return; }
+
+        // Finished boards are valid solutions of the puzzle
+        for (x, y) in (0..81).map(|i| (i % 9, i / 9)) {
+            if puzzle.rows[y][x].is_some() { assert_eq!(solution.rows[y][x], puzzle.rows[y][x], "Solution does not keep given ({x},{y}) of puzzle:\n{puzzle}\n{solution}"); }
+        }
+
+        // Solving is idempotent
+        assert_eq!(new_solver().run(solution), solution, "Solving a solution changed it:\n{solution}");
+
+        // Restricting the solution to the givens plus a random subset of the solved cells re-solves to a solution that keeps all of them
+        let mut rng: StdRng = StdRng::seed_from_u64(42);
+        let restricted: Sudoku = Sudoku::from_fn(|x, y| if puzzle.rows[y][x].is_some() || rng.gen_bool(0.5) { solution.rows[y][x] } else { None });
+        let resolved: Sudoku = new_solver().run(restricted);
+        assert!(resolved.is_finished(), "Restricted solution did not re-solve:\n{restricted}");
+        for (x, y) in (0..81).map(|i| (i % 9, i / 9)) {
+            if restricted.rows[y][x].is_some() { assert_eq!(resolved.rows[y][x], restricted.rows[y][x], "Re-solved solution does not keep given ({x},{y}) of restricted solution:\n{restricted}\n{resolved}"); }
+        }
+    }
+
     #[test]
     fn test_brute_force_solver() {
         // Test an empty Sudoku can be solved
@@ -44,6 +106,699 @@ mod tests {
             assert_eq!(correct, solved);
         }
     }
+
+    #[test]
+    fn test_stochastic_solver() {
+        // Test if an easy Sudoku is solved
+        let easy: Sudoku = load_sudoku("./tests/example.sdk").unwrap_or_else(|err| panic!("Failed to load easy Sudoku: {}", err.pretty())).swap_remove(0);
+        println!("\n{easy}");
+
+        let mut solver: StochasticSolver = StochasticSolver::with_seed(42);
+        let solved: Sudoku = solver.run(easy);
+        println!("{}", solved.masked(&easy));
+        assert!(solved.is_finished());
+        for y in 0..9 {
+            for x in 0..9 {
+                if easy.rows[y][x].is_some() { assert_eq!(easy.rows[y][x], solved.rows[y][x]); }
+            }
+        }
+    }
+
+
+    #[test]
+    fn test_brute_force_solver_heat() {
+        let hard: Sudoku = load_sudoku("./tests/example.sdm").unwrap_or_else(|err| panic!("Failed to load hard Sudoku: {}", err.pretty())).swap_remove(3);
+        println!("\n{hard}");
+
+        let mut solver: BruteForceSolver = BruteForceSolver::new();
+        let (solved, heat): (Sudoku, [ [ u32; 9 ]; 9 ]) = solver.run_with_heat(hard);
+        assert!(solved.is_finished());
+        println!("{heat:?}");
+
+        // Givens are never assigned
+        let preprocessed: Sudoku = solver.preprocess(hard);
+        for (y, row) in preprocessed.rows.iter().enumerate() {
+            for (x, cell) in row.iter().enumerate() {
+                if cell.is_some() { assert_eq!(heat[y][x], 0); }
+            }
+        }
+
+        // The first expanded cell is assigned once for every candidate, while deeper cells are assigned over and over again
+        let (x, y): (usize, usize) = (0..81).map(|i| (i % 9, i / 9)).find(|(x, y)| preprocessed.rows[*y][*x].is_none()).unwrap();
+        assert!(heat[y][x] > 0);
+        assert!(heat[y][x] <= 9);
+        assert!(heat.iter().flatten().max().unwrap() > &heat[y][x]);
+    }
+
+    #[test]
+    fn test_brute_force_solver_stats() {
+        let hard: Sudoku = load_sudoku("./tests/example.sdm").unwrap_or_else(|err| panic!("Failed to load hard Sudoku: {}", err.pretty())).swap_remove(3);
+        let mut solver: BruteForceSolver = BruteForceSolver::new();
+        let (solved, stats): (Sudoku, SolveStats) = solver.run_with_stats(hard);
+        assert!(solved.is_finished());
+        assert_eq!(solved, solver.run(hard));
+
+        // The search went all the way down, and had to backtrack along the way
+        let n_empty: usize = solver.preprocess(hard).count_empty();
+        assert_eq!(stats.max_depth, n_empty);
+        assert!(stats.nodes >= n_empty);
+        assert!(stats.backtracks > 0);
+
+        // The report has all the fields
+        let report: String = stats.to_string();
+        println!("{report}");
+        for field in [ "Nodes expanded", "Maximum depth", "Backtracks", "Time taken" ] {
+            let line: &str = report.lines().find(|l| l.starts_with(field)).unwrap_or_else(|| panic!("Missing field '{field}' in report"));
+            assert!(line.split(':').nth(1).map(|v| v.trim().trim_end_matches("ms").parse::<u128>().is_ok()).unwrap_or(false));
+        }
+    }
+
+    #[test]
+    fn test_brute_force_solver_steps() {
+        let puzzle: Sudoku = load_sudoku("./tests/example.sdm").unwrap_or_else(|err| panic!("Failed to load Sudokus: {}", err.pretty())).swap_remove(3);
+        let mut solver: BruteForceSolver = BruteForceSolver::new();
+
+        // The first few steps are valid intermediate boards that only add to the puzzle
+        let first: Vec<Sudoku> = solver.steps(puzzle).take(5).collect();
+        assert_eq!(first.len(), 5);
+        for step in &first {
+            assert!(step.is_well_formed());
+            assert!((0..81).all(|i| puzzle.rows[i / 9][i % 9].is_none() || step.rows[i / 9][i % 9] == puzzle.rows[i / 9][i % 9]));
+        }
+
+        // All steps are the same as the ones given to the callback, followed by the solution
+        let mut attempts: Vec<Sudoku> = vec![];
+        let solution: Sudoku = solver.run_with_callback(puzzle, |attempt| { attempts.push(*attempt); Ok::<bool, core::convert::Infallible>(true) }).unwrap().unwrap();
+        attempts.push(solution);
+        assert_eq!(solver.steps(puzzle).collect::<Vec<Sudoku>>(), attempts);
+        assert!(solver.steps(puzzle).last().unwrap().is_finished());
+    }
+
+    #[test]
+    fn test_brute_force_solver_best_attempt() {
+        // Break a puzzle with a given that doesn't conflict with any peer, but that isn't the solution either
+        let puzzle: Sudoku = load_sudoku("./tests/example.sdm").unwrap_or_else(|err| panic!("Failed to load Sudokus: {}", err.pretty())).swap_remove(3);
+        let mut solver: BruteForceSolver = BruteForceSolver::new();
+        let solution: Sudoku = solver.run(puzzle);
+        let (x, y, value): (usize, usize, u8) = (0..81).map(|i| (i % 9, i / 9)).filter(|(x, y)| puzzle.rows[*y][*x].is_none()).find_map(|(x, y)| {
+            puzzle.candidates(x, y).into_iter().find(|v| Some(*v) != solution.rows[y][x]).map(|v| (x, y, v))
+        }).unwrap();
+        let mut broken: Sudoku = puzzle;
+        broken.rows[y][x] = Some(value);
+        assert!(broken.is_well_formed());
+
+        // The search exhausts without a solution, and returns the first of the fullest attempts it visited
+        let mut best: Option<Sudoku> = None;
+        let attempt: Sudoku = solver.run_with_callback(broken, |attempt| {
+            if best.map(|best| attempt.count_empty() < best.count_empty()).unwrap_or(true) { best = Some(*attempt); }
+            Ok::<bool, core::convert::Infallible>(true)
+        }).unwrap().unwrap();
+        println!("{attempt}");
+        assert!(!attempt.is_finished());
+        assert!(attempt.is_well_formed());
+        assert!(broken.equals_ignoring_empties(&attempt));
+        assert!(attempt.count_empty() < solver.preprocess(broken).count_empty());
+        assert_eq!(Some(attempt), best);
+    }
+
+    #[test]
+    fn test_backtracking_solver() {
+        let mut boards: Vec<Sudoku> = load_sudoku("./tests/example.sdm").unwrap_or_else(|err| panic!("Failed to load Sudokus: {}", err.pretty()));
+        boards.extend(load_sudoku("./tests/example.sdk").unwrap_or_else(|err| panic!("Failed to load Sudoku: {}", err.pretty())));
+        boards.extend(load_sudoku("./tests/correct.json").unwrap_or_else(|err| panic!("Failed to load Sudoku: {}", err.pretty())));
+
+        // It visits the same states as the copy-based solver, and thus finds the same solutions
+        for puzzle in [ boards[3], boards[8], boards[9] ] {
+            let (mut expected, mut got): (Vec<Sudoku>, Vec<Sudoku>) = (vec![], vec![]);
+            let solution: Sudoku = BruteForceSolver::new().run_with_callback(puzzle, |s| { expected.push(*s); Ok::<bool, core::convert::Infallible>(true) }).unwrap().unwrap();
+            assert_eq!(BacktrackingSolver::new().run_with_callback(puzzle, |s| { got.push(*s); Ok::<bool, core::convert::Infallible>(true) }).unwrap(), Some(solution));
+            assert!(solution.is_finished());
+            assert_eq!(got, expected);
+        }
+
+        // Ill-formed Sudokus cannot be solved
+        let mut sudoku: Sudoku = Sudoku::empty();
+        sudoku.rows[0][0] = Some(1);
+        sudoku.rows[0][1] = Some(1);
+        assert!(!BacktrackingSolver::new().run(sudoku).is_finished());
+
+        // Near-empty boards are searched to the maximum depth without issue, unless limited
+        let mut sudoku: Sudoku = Sudoku::empty();
+        sudoku.rows[4][4] = Some(5);
+        let solution: Sudoku = BacktrackingSolver::new().run(sudoku);
+        assert!(solution.is_finished());
+        assert_eq!(solution.rows[4][4], Some(5));
+        assert!(!BacktrackingSolver::with_max_depth(40).run(sudoku).is_finished());
+        assert!(BacktrackingSolver::with_max_depth(1000).run(sudoku).is_finished());
+    }
+
+    #[test]
+    fn test_constraint_propagation_solver() {
+        // Already solved and empty boards are finished
+        let correct: Sudoku = load_sudoku("./tests/correct.json").unwrap_or_else(|err| panic!("Failed to load Sudoku: {}", err.pretty())).swap_remove(0);
+        assert_eq!(ConstraintPropagationSolver::new().run(correct), correct);
+        let empty: Sudoku = load_sudoku("./tests/empty.json").unwrap_or_else(|err| panic!("Failed to load Sudoku: {}", err.pretty())).swap_remove(0);
+        assert!(ConstraintPropagationSolver::new().run(empty).is_finished());
+
+        // Typical puzzles are solved in far fewer steps than by brute force
+        let boards: Vec<Sudoku> = load_sudoku("./tests/example.sdm").unwrap_or_else(|err| panic!("Failed to load Sudokus: {}", err.pretty()));
+        for puzzle in [ boards[3], boards[5], boards[6] ] {
+            let (mut steps, mut brute_steps): (usize, usize) = (0, 0);
+            let solution: Sudoku = ConstraintPropagationSolver::new().run_with_callback(puzzle, |_| { steps += 1; Ok::<bool, core::convert::Infallible>(true) }).unwrap().unwrap();
+            assert_eq!(solution, BruteForceSolver::new().run_with_callback(puzzle, |_| { brute_steps += 1; Ok::<bool, core::convert::Infallible>(true) }).unwrap().unwrap());
+            assert!(solution.is_finished());
+            assert!(steps * 10 < brute_steps, "Took {steps} steps, against {brute_steps} for brute force");
+        }
+
+        // Contradictory ones are found out, both up-front and by propagation
+        let mut sudoku: Sudoku = Sudoku::empty();
+        sudoku.rows[0][0] = Some(1);
+        sudoku.rows[0][1] = Some(1);
+        assert!(!ConstraintPropagationSolver::new().run(sudoku).is_finished());
+        let mut grid: CandidateGrid = Sudoku::empty().to_candidates();
+        grid.rows[0][0] = 1 << 1;
+        grid.rows[0][1] = 1 << 1;
+        assert!(!ConstraintPropagationSolver::propagate(&mut grid));
+    }
+
+    #[test]
+    fn test_certifying_solver() {
+        let puzzle: Sudoku = load_sudoku("./tests/example.sdk").unwrap_or_else(|err| panic!("Failed to load Sudoku: {}", err.pretty())).swap_remove(0);
+        let mut solver: CertifyingSolver = CertifyingSolver::new();
+
+        // Solvable Sudokus have no certificate
+        assert!(solver.run(puzzle).is_finished());
+        assert_eq!(solver.certificate(), None);
+
+        // Break it with a single given that doesn't conflict with any peer, but that isn't the solution either
+        let solution: Sudoku = BruteForceSolver::new().run(puzzle);
+        let (x, y, value): (usize, usize, u8) = (0..81).map(|i| (i % 9, i / 9)).filter(|(x, y)| puzzle.rows[*y][*x].is_none()).find_map(|(x, y)| {
+            puzzle.candidates(x, y).into_iter().find(|v| Some(*v) != solution.rows[y][x]).map(|v| (x, y, v))
+        }).unwrap();
+        let mut broken: Sudoku = puzzle;
+        broken.rows[y][x] = Some(value);
+        assert!(broken.is_well_formed());
+        assert!(!solver.run(broken).is_finished());
+
+        // The certificate points at the bad given, and is itself unsolvable
+        let certificate: Vec<(usize, usize)> = solver.certificate().unwrap().to_vec();
+        assert!(certificate.contains(&(x, y)));
+        assert!(certificate.len() < broken.rows.iter().flatten().filter(|c| c.is_some()).count());
+        let core: Sudoku = Sudoku::from_fn(|cx, cy| if certificate.contains(&(cx, cy)) { broken.rows[cy][cx] } else { None });
+        assert_eq!(CertifyingSolver::explain(&core), Some(certificate));
+    }
+
+    #[test]
+    fn test_solver_finished() {
+        let correct: Sudoku = load_sudoku("./tests/correct.json").unwrap_or_else(|err| panic!("Failed to load Sudoku: {}", err.pretty())).swap_remove(0);
+        let empty: Sudoku = load_sudoku("./tests/empty.json").unwrap_or_else(|err| panic!("Failed to load Sudoku: {}", err.pretty())).swap_remove(0);
+        assert_eq!(correct.count_empty(), 0);
+        assert_eq!(empty.count_empty(), 81);
+
+        // Finished Sudokus are returned as-is, without ever calling the callback
+        let mut n_calls: usize = 0;
+        let mut callback = |_: &Sudoku| -> Result<bool, core::convert::Infallible> { n_calls += 1; Ok(true) };
+        assert_eq!(BruteForceSolver::new().run_with_callback(correct, &mut callback).unwrap(), Some(correct));
+        assert_eq!(StochasticSolver::with_seed(42).run_with_callback(correct, &mut callback).unwrap(), Some(correct));
+        assert_eq!(CertifyingSolver::new().run_with_callback(correct, &mut callback).unwrap(), Some(correct));
+        assert_eq!(n_calls, 0);
+        assert_eq!(BruteForceSolver::new().run_with_stats(correct).1.nodes, 0);
+    }
+
+    #[test]
+    fn test_solver_invariants() {
+        for seed in 0..20 {
+            let puzzle: Sudoku = random_puzzle(&mut StdRng::seed_from_u64(seed));
+            assert!(puzzle.is_well_formed());
+            check_invariants(BruteForceSolver::new, puzzle);
+            check_invariants(CertifyingSolver::new, puzzle);
+            check_invariants(BacktrackingSolver::new, puzzle);
+            check_invariants(ConstraintPropagationSolver::new, puzzle);
+            // The brute-force solver always finds one, since the puzzle has a solution
+            assert!(BruteForceSolver::new().run(puzzle).is_finished());
+        }
+        for seed in 0..3 {
+            check_invariants(|| StochasticSolver::with_seed(42), random_puzzle(&mut StdRng::seed_from_u64(seed)));
+        }
+    }
+
+    #[test]
+    fn test_solution_cache() {
+        /// Counts how often the nested solver actually ran.
+        struct CountingSolver { solver: BruteForceSolver, runs: usize }
+        impl Solver for CountingSolver {
+            fn run_with_callback<E>(&mut self, sudoku: Sudoku, callback: impl FnMut(&Sudoku) -> Result<bool, E>) -> Result<Option<Sudoku>, E> {
+                self.runs += 1;
+                self.solver.run_with_callback(sudoku, callback)
+            }
+        }
+
+        // Solve a list with duplicates
+        let sudokus: Vec<Sudoku> = load_sudoku("./tests/example.sdm").unwrap_or_else(|err| panic!("Failed to load Sudokus: {}", err.pretty()));
+        let puzzles: Vec<Sudoku> = vec![ sudokus[3], sudokus[5], sudokus[3], sudokus[3], sudokus[5] ];
+        let mut solver: CountingSolver = CountingSolver { solver: BruteForceSolver::new(), runs: 0 };
+        let mut cache: SolutionCache = SolutionCache::new();
+        let solutions: Vec<Sudoku> = puzzles.iter().map(|s| cache.solve(&mut solver, *s)).collect();
+
+        // Only the unique puzzles were solved, and the solutions are the same as without cache
+        assert_eq!(solver.runs, 2);
+        assert_eq!(cache.len(), 2);
+        for (puzzle, solution) in puzzles.iter().zip(solutions.iter()) {
+            assert_eq!(*solution, BruteForceSolver::new().run(*puzzle));
+        }
+    }
+
+    #[test]
+    fn test_solvers_agree() {
+        let sudokus: Vec<Sudoku> = load_sudoku("./tests/example.sdm").unwrap_or_else(|err| panic!("Failed to load Sudokus: {}", err.pretty()));
+        let mut boards: Vec<Sudoku> = vec![ sudokus[3], sudokus[5] ];
+        boards.extend(load_sudoku("./tests/example.sdk").unwrap_or_else(|err| panic!("Failed to load Sudoku: {}", err.pretty())));
+        assert_eq!(solvers_agree(&mut BruteForceSolver::new(), &mut BruteForceSolver::new(), &boards), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn test_solver_solutions() {
+        let mut solver: BruteForceSolver = BruteForceSolver::new();
+
+        // An empty Sudoku has loads of solutions
+        let solutions: Vec<Sudoku> = solver.solutions(Sudoku::empty(), 5);
+        assert_eq!(solutions.len(), 5);
+        for (i, solution) in solutions.iter().enumerate() {
+            assert!(solution.is_finished());
+            assert!(!solutions[i + 1..].contains(solution));
+        }
+
+        // A correct Sudoku has only itself
+        let correct: Sudoku = load_sudoku("./tests/correct.json").unwrap_or_else(|err| panic!("Failed to load correct Sudoku: {}", err.pretty())).swap_remove(0);
+        assert_eq!(solver.solutions(correct, 5), vec![ correct ]);
+
+        // A puzzle with a deadly rectangle has exactly two
+        let mut sudoku: Sudoku = correct;
+        for (x, y) in [ (2, 0), (3, 0), (2, 1), (3, 1) ] {
+            sudoku.rows[y][x] = None;
+        }
+        let solutions: Vec<Sudoku> = solver.solutions(sudoku, 5);
+        assert_eq!(solutions.len(), 2);
+        assert!(solutions.contains(&correct));
+        assert_eq!(solver.solutions(sudoku, 1).len(), 1);
+        assert_eq!(solver.solutions(sudoku, 0).len(), 0);
+
+        // An ill-formed Sudoku has none
+        let mut sudoku: Sudoku = Sudoku::empty();
+        sudoku.rows[0][0] = Some(1);
+        sudoku.rows[0][1] = Some(1);
+        assert!(solver.solutions(sudoku, 5).is_empty());
+    }
+    #[test]
+    fn test_solver_run_with_restrictions() {
+        let puzzle: Sudoku = load_sudoku("./tests/example.sdk").unwrap_or_else(|err| panic!("Failed to load Sudoku: {}", err.pretty())).swap_remove(0);
+        let mut solver: BruteForceSolver = BruteForceSolver::new();
+        let solution: Sudoku = solver.run(puzzle);
+        assert_eq!(solver.solutions(puzzle, 2), vec![ solution ]);
+
+        // Without restrictions, it finds the unique solution
+        let mut forbidden: [ [ u16; 9 ]; 9 ] = [ [ 0; 9 ]; 9 ];
+        assert_eq!(solver.run_with_restrictions(puzzle, &forbidden), Some(solution));
+
+        // Forbidding anything but the solution doesn't change that
+        forbidden[0][1] = 0b11_1111_1110 & !(1 << solution.rows[0][1].unwrap());
+        assert_eq!(solver.run_with_restrictions(puzzle, &forbidden), Some(solution));
+
+        // But forbidding the correct value of an empty cell makes it unsolvable
+        let mut forbidden: [ [ u16; 9 ]; 9 ] = [ [ 0; 9 ]; 9 ];
+        forbidden[0][1] = 1 << solution.rows[0][1].unwrap();
+        assert_eq!(solver.run_with_restrictions(puzzle, &forbidden), None);
+
+        // As does forbidding a given
+        let mut forbidden: [ [ u16; 9 ]; 9 ] = [ [ 0; 9 ]; 9 ];
+        forbidden[0][0] = 1 << puzzle.rows[0][0].unwrap();
+        assert_eq!(solver.run_with_restrictions(puzzle, &forbidden), None);
+
+        // Values outside of the 1-9 range are never allowed (nor do they overflow the mask)
+        let mut large: Sudoku = puzzle;
+        large.rows[0][1] = Some(20);
+        assert_eq!(solver.run_with_restrictions(large, &[ [ 0; 9 ]; 9 ]), None);
+    }
+
+    #[test]
+    fn test_solver_as_fn() {
+        let mut puzzles: Vec<Sudoku> = load_sudoku("./tests/example.sdk").unwrap_or_else(|err| panic!("Failed to load Sudoku: {}", err.pretty()));
+        puzzles.push(load_sudoku("./tests/example.sdm").unwrap_or_else(|err| panic!("Failed to load Sudokus: {}", err.pretty())).swap_remove(3));
+        puzzles.push(Sudoku::empty());
+        let mut solver: BruteForceSolver = BruteForceSolver::new();
+        let solutions: Vec<Sudoku> = puzzles.iter().copied().map(solver.as_fn()).collect();
+        assert_eq!(solutions.len(), puzzles.len());
+        for (puzzle, solution) in puzzles.iter().zip(&solutions) {
+            assert!(solution.is_finished());
+            assert!(puzzle.equals_ignoring_empties(solution));
+        }
+    }
+
+    #[test]
+    fn test_solver_uniqueness() {
+        let puzzle: Sudoku = load_sudoku("./tests/example.sdk").unwrap_or_else(|err| panic!("Failed to load Sudoku: {}", err.pretty())).swap_remove(0);
+        let mut solver: BruteForceSolver = BruteForceSolver::new();
+        assert_eq!(solver.uniqueness(puzzle), Uniqueness::Unique);
+
+        // The empty board stops at the second solution instead of enumerating all of them
+        assert_eq!(solver.uniqueness(Sudoku::empty()), Uniqueness::Multiple);
+        assert_eq!(solver.solutions(Sudoku::empty(), 2).len(), 2);
+        let mut n_found: usize = 0;
+        enumerate_solutions(Sudoku::empty(), &[ [ 0; 9 ]; 9 ], |_| { n_found += 1; n_found < 2 });
+        assert_eq!(n_found, 2);
+
+        // Contradictory boards have none
+        let mut sudoku: Sudoku = Sudoku::empty();
+        sudoku.rows[0][0] = Some(1);
+        sudoku.rows[0][1] = Some(1);
+        assert_eq!(solver.uniqueness(sudoku), Uniqueness::NoSolution);
+    }
+
+    #[test]
+    fn test_solver_solve_closest_to() {
+        // Remove a deadly rectangle to get a puzzle with exactly two solutions
+        let correct: Sudoku = load_sudoku("./tests/correct.json").unwrap_or_else(|err| panic!("Failed to load correct Sudoku: {}", err.pretty())).swap_remove(0);
+        let mut sudoku: Sudoku = correct;
+        for (x, y) in [ (2, 0), (3, 0), (2, 1), (3, 1) ] {
+            sudoku.rows[y][x] = None;
+        }
+        let mut solver: BruteForceSolver = BruteForceSolver::new();
+        let solutions: Vec<Sudoku> = solver.solutions(sudoku, 5);
+        assert_eq!(solutions.len(), 2);
+
+        // Either one is found when it is the target, even if the target only hints at it
+        for solution in &solutions {
+            assert_eq!(solver.solve_closest_to(sudoku, solution), Some(*solution));
+            let hint: Sudoku = Sudoku::from_fn(|x, y| if (x, y) == (2, 0) { solution.rows[y][x] } else { None });
+            assert_eq!(solver.solve_closest_to(sudoku, &hint), Some(*solution));
+        }
+
+        // Unsolvable Sudokus have nothing close
+        let mut sudoku: Sudoku = Sudoku::empty();
+        sudoku.rows[0][0] = Some(1);
+        sudoku.rows[0][1] = Some(1);
+        assert_eq!(solver.solve_closest_to(sudoku, &correct), None);
+    }
+
+    #[test]
+    fn test_solver_solutions_tie_break() {
+        // Remove a deadly rectangle, such that all four empty cells tie with two candidates each
+        let correct: Sudoku = load_sudoku("./tests/correct.json").unwrap_or_else(|err| panic!("Failed to load correct Sudoku: {}", err.pretty())).swap_remove(0);
+        let mut sudoku: Sudoku = correct;
+        for (x, y) in [ (2, 0), (3, 0), (2, 1), (3, 1) ] {
+            sudoku.rows[y][x] = None;
+        }
+        for (x, y) in [ (2, 0), (3, 0), (2, 1), (3, 1) ] {
+            assert_eq!(sudoku.candidates(x, y).len(), 2);
+        }
+
+        // The first cell in row-major order is expanded first (with its lowest candidate), so the first solution found always has that in the top-left empty cell
+        let lowest: u8 = sudoku.candidates(2, 0)[0];
+        let mut solver: BruteForceSolver = BruteForceSolver::new();
+        let first: Vec<Sudoku> = solver.solutions(sudoku, 1);
+        assert_eq!(first.len(), 1);
+        assert_eq!(first[0].rows[0][2], Some(lowest));
+        assert_ne!(first[0].rows[0][3], Some(lowest));
+        for _ in 0..10 {
+            assert_eq!(solver.solutions(sudoku, 1), first);
+            assert_eq!(solver.solutions(sudoku, 2)[0], first[0]);
+        }
+    }
+
+    #[test]
+    fn test_solver_preprocess() {
+        // Remove a forced cell and a deadly rectangle that cannot be solved by naked singles alone
+        let correct: Sudoku = load_sudoku("./tests/correct.json").unwrap_or_else(|err| panic!("Failed to load correct Sudoku: {}", err.pretty())).swap_remove(0);
+        let mut sudoku: Sudoku = correct;
+        for (x, y) in [ (8, 8), (2, 0), (3, 0), (2, 1), (3, 1) ] {
+            sudoku.rows[y][x] = None;
+        }
+        println!("\n{sudoku}");
+
+        // Preprocessing only fills the forced cell
+        let mut solver: BruteForceSolver = BruteForceSolver::new();
+        let preprocessed: Sudoku = solver.preprocess(sudoku);
+        println!("{preprocessed}");
+        assert_eq!(preprocessed.rows[8][8], Some(9));
+        assert_eq!(preprocessed.score(), 77.0 / 81.0);
+
+        // The first callback already sees the preprocessed Sudoku
+        let mut first: Option<Sudoku> = None;
+        let solved: Option<Sudoku> = solver.run_with_callback(sudoku, |s| {
+            if first.is_none() { first = Some(*s); }
+            Ok::<bool, core::convert::Infallible>(true)
+        }).unwrap();
+        assert_eq!(first, Some(preprocessed));
+        assert!(solved.unwrap().is_finished());
+    }
+
+    #[test]
+    fn test_sudoku_steps_before_guess() {
+        let easy: Sudoku = load_sudoku("./tests/example.sdk").unwrap_or_else(|err| panic!("Failed to load Sudoku: {}", err.pretty())).swap_remove(0);
+        let boards: Vec<Sudoku> = load_sudoku("./tests/example.sdm").unwrap_or_else(|err| panic!("Failed to load Sudokus: {}", err.pretty()));
+        assert_eq!(easy.steps_before_guess(), easy.count_empty());
+        for sudoku in &boards {
+            let steps: usize = sudoku.steps_before_guess();
+            assert!(steps > 0 && steps < sudoku.count_empty());
+            assert!(steps >= sudoku.trivial_fill_count());
+        }
+        assert_eq!(boards[5].steps_before_guess(), 12);
+        assert_eq!(Sudoku::empty().steps_before_guess(), 0);
+    }
+
+    #[test]
+    fn test_sudoku_suggest_repair() {
+        let puzzle: Sudoku = load_sudoku("./tests/example.sdk").unwrap_or_else(|err| panic!("Failed to load Sudoku: {}", err.pretty())).swap_remove(0);
+        assert_eq!(puzzle.suggest_repair(), None);
+
+        // Mistype an empty cell with the value of an earlier given in the same row; only clearing the typo leaves a solvable board
+        let mut typo: Sudoku = puzzle;
+        typo.rows[0][4] = puzzle.rows[0][3];
+        assert!(!typo.is_well_formed());
+        assert_eq!(typo.suggest_repair(), Some((4, 0)));
+
+        // Multiple conflicting typos cannot be repaired by a single cell
+        typo.rows[1][0] = puzzle.rows[1][1];
+        typo.rows[7][8] = puzzle.rows[7][7];
+        assert_eq!(typo.suggest_repair(), None);
+    }
+
+    #[test]
+    fn test_sudoku_minimize_hard() {
+        let sudoku: Sudoku = load_sudoku("./tests/example.sdk").unwrap_or_else(|err| panic!("Failed to load Sudoku: {}", err.pretty())).swap_remove(0);
+        let solution: Vec<Sudoku> = BruteForceSolver::new().solutions(sudoku, 2);
+        assert_eq!(solution.len(), 1);
+
+        // Even without any budget, a full removal order is tried; the minimized puzzle has a subset of the clues, but still the same unique solution
+        let minimized: Sudoku = sudoku.minimize_hard(Duration::ZERO, &mut StdRng::seed_from_u64(42));
+        println!("{sudoku}\n{minimized}");
+        assert_eq!(BruteForceSolver::new().solutions(minimized, 2), solution);
+        for (row, orow) in minimized.rows.iter().zip(sudoku.rows.iter()) {
+            for (cell, ocell) in row.iter().zip(orow.iter()) {
+                if cell.is_some() { assert_eq!(cell, ocell); }
+            }
+        }
+        assert!(minimized.rows.iter().flatten().filter(|c| c.is_some()).count() < sudoku.rows.iter().flatten().filter(|c| c.is_some()).count());
+
+        // More time never makes it worse
+        let longer: Sudoku = sudoku.minimize_hard(Duration::from_millis(50), &mut StdRng::seed_from_u64(42));
+        assert!(longer.count_empty() >= minimized.count_empty());
+        assert_eq!(BruteForceSolver::new().solutions(longer, 2), solution);
+
+        // Sudokus without a unique solution are left alone
+        assert_eq!(Sudoku::empty().minimize_hard(Duration::ZERO, &mut StdRng::seed_from_u64(42)), Sudoku::empty());
+    }
+}
+
+
+
+
+
+/***** HELPER FUNCTIONS *****/
+/// Runs a search and records how long it took in its [`SolveStats::time`].
+/// 
+/// # Arguments
+/// - `search`: The search to run. Returns its result and statistics, or an error.
+/// 
+/// # Returns
+/// The result of the `search`, with the wall time it took set in its [`SolveStats`].
+#[cfg(feature = "std")]
+fn timed<T, E>(search: impl FnOnce() -> Result<(T, SolveStats), E>) -> Result<(T, SolveStats), E> {
+    let start: Instant = Instant::now();
+    let (res, mut stats): (T, SolveStats) = search()?;
+    stats.time = start.elapsed();
+    Ok((res, stats))
+}
+
+/// Runs a search as-is, since there is no clock to time it with without the `std` feature.
+/// 
+/// # Arguments
+/// - `search`: The search to run. Returns its result and statistics, or an error.
+/// 
+/// # Returns
+/// The result of the `search`, with its [`SolveStats::time`] left at zero.
+#[cfg(not(feature = "std"))]
+#[inline]
+fn timed<T, E>(search: impl FnOnce() -> Result<(T, SolveStats), E>) -> Result<(T, SolveStats), E> { search() }
+
+/// Takes a single step of the [`BruteForceSolver`]'s depth-first search.
+/// 
+/// Pops the next attempt off the `search_space` and, if it's well-formed but not yet finished, pushes a new attempt for every valid value of its first empty cell.
+/// 
+/// # Arguments
+/// - `search_space`: The attempts still to explore. The last one is explored first.
+/// - `on_assign`: A function that is called with the coordinates of a cell every time it is assigned a value.
+/// 
+/// # Returns
+/// What happened to the attempt that was popped, or [`None`] if the `search_space` was empty.
+fn brute_force_step(search_space: &mut Vec<Sudoku>, mut on_assign: impl FnMut(usize, usize)) -> Option<BruteForceStep> {
+    let attempt: Sudoku = search_space.pop()?;
+    if !attempt.is_well_formed() { return Some(BruteForceStep::Discarded); }
+    if attempt.is_finished() { return Some(BruteForceStep::Finished(attempt)); }
+
+    // Expand the first empty cell
+    let n_options: usize = search_space.len();
+    if let Some((x, y)) = (0..81).map(|i| (i % 9, i / 9)).find(|(x, y)| attempt.rows[*y][*x].is_none()) {
+        for v in (1..=9).filter(|v| attempt.is_cell_valid(x, y, *v)) {
+            let mut next_attempt: Sudoku = attempt;
+            next_attempt.rows[y][x] = Some(v);
+            search_space.push(next_attempt);
+            on_assign(x, y);
+        }
+    }
+    Some(BruteForceStep::Expanded(attempt, search_space.len() - n_options))
+}
+
+/// Enumerates the solutions of a Sudoku with an exhaustive, depth-first search.
+/// 
+/// At every step, the empty cell with the fewest candidates is expanded (preferring the first in row-major order on ties).
+/// 
+/// # Arguments
+/// - `sudoku`: The [`Sudoku`] to find the solutions of.
+/// - `forbidden`: A bitmask per cell of values it may not take on top of the rules, where bit `v` is set iff `v` is forbidden (see [`Solver::run_with_restrictions()`]).
+/// - `callback`: A function that is called with every solution found. Returns whether to continue searching (true) or not (false).
+fn enumerate_solutions(sudoku: Sudoku, forbidden: &[ [ u16; 9 ]; 9 ], mut callback: impl FnMut(Sudoku) -> bool) {
+    // Ill-formed Sudokus have no solutions, nor do Sudokus with forbidden values already filled-in
+    if !sudoku.is_well_formed() { return; }
+    let allowed = |x: usize, y: usize, v: u8| -> bool { (1..=9).contains(&v) && forbidden[y][x] & (1 << v) == 0 };
+    if (0..81).any(|i| sudoku.rows[i / 9][i % 9].map(|v| !allowed(i % 9, i / 9, v)).unwrap_or(false)) { return; }
+
+    let mut search_space: Vec<Sudoku> = vec![ sudoku ];
+    while let Some(attempt) = search_space.pop() {
+        // Find the most constrained empty cell
+        let mut best: Option<(usize, usize, Vec<u8>)> = None;
+        'empty_cell: for y in 0..9 {
+            for x in 0..9 {
+                if attempt.rows[y][x].is_some() { continue; }
+                let candidates: Vec<u8> = attempt.candidates(x, y).into_iter().filter(|v| allowed(x, y, *v)).collect();
+                if best.as_ref().map(|(_, _, c)| candidates.len() < c.len()).unwrap_or(true) {
+                    let dead_end: bool = candidates.is_empty();
+                    best = Some((x, y, candidates));
+                    if dead_end { break 'empty_cell; }
+                }
+            }
+        }
+
+        // Either it's a solution, or we branch on the cell's candidates
+        match best {
+            Some((x, y, candidates)) => {
+                for v in candidates.into_iter().rev() {
+                    let mut next_attempt: Sudoku = attempt;
+                    next_attempt.rows[y][x] = Some(v);
+                    search_space.push(next_attempt);
+                }
+            },
+            None => { if !callback(attempt) { return; } },
+        }
+    }
+}
+
+
+
+
+
+/***** LIBRARY FUNCTIONS *****/
+/// Compares two solvers on a collection of Sudokus, e.g., for regression testing a new solver.
+/// 
+/// Note that the solvers are taken generically, as [`Solver`] is not object-safe (see [`Solver::run_with_callback()`]).
+/// 
+/// # Arguments
+/// - `a`: The first [`Solver`] to compare.
+/// - `b`: The second [`Solver`] to compare.
+/// - `boards`: The [`Sudoku`]s to solve with both. For uniquely-solvable boards, the solvers should agree.
+/// 
+/// # Returns
+/// The indices (in `boards`) of the Sudokus for which both solvers produced different solutions.
+pub fn solvers_agree(a: &mut impl Solver, b: &mut impl Solver, boards: &[Sudoku]) -> Vec<usize> {
+    boards.iter().enumerate().filter(|(_, board)| a.run(**board) != b.run(**board)).map(|(i, _)| i).collect()
+}
+
+// The Sudoku's methods that need a solver are implemented here instead of next to the rest, such that the model itself does not depend on any solver.
+impl Sudoku {
+    /// Counts how many cells can be filled-in by pure logic before guessing becomes necessary.
+    /// 
+    /// This uses the naked and hidden singles of the [`ConstraintPropagationSolver`], and is thus a finer measure of difficulty than [`Sudoku::trivial_fill_count()`].
+    /// 
+    /// # Returns
+    /// The number of empty cells that are decided before the first guess. This is the number of empty cells if the Sudoku can be solved by logic alone, or 0 if it's contradictory.
+    pub fn steps_before_guess(&self) -> usize {
+        if !self.is_well_formed() { return 0; }
+        let mut grid: CandidateGrid = self.to_candidates();
+        if !ConstraintPropagationSolver::propagate(&mut grid) { return 0; }
+        (0..81).filter(|i| self.rows[i / 9][i % 9].is_none() && grid.single(i % 9, i / 9).is_some()).count()
+    }
+
+    /// Suggests a single cell to clear to make an ill-formed Sudoku well-formed again, e.g., to fix a typo in a given.
+    /// 
+    /// If multiple cells would do, this prefers the first one (in row-major order) after which the Sudoku is still solvable, since that's likely the erroneous one.
+    /// 
+    /// # Returns
+    /// The `(x, y)` coordinates of a cell whose clearing resolves all conflicts, or [`None`] if the Sudoku is already [well-formed](Sudoku::well_formed()) or if no single cell would do.
+    pub fn suggest_repair(&self) -> Option<(usize, usize)> {
+        if self.is_well_formed() { return None; }
+
+        // Find all cells whose clearing resolves the conflicts
+        let cleared = |x: usize, y: usize| -> Sudoku { let mut sudoku: Sudoku = *self; sudoku.rows[y][x] = None; sudoku };
+        let repairs: Vec<(usize, usize)> = (0..81).map(|i| (i % 9, i / 9)).filter(|(x, y)| self.rows[*y][*x].is_some() && cleared(*x, *y).is_well_formed()).collect();
+
+        // Prefer the one that leaves a solvable Sudoku
+        repairs.iter().find(|(x, y)| !BruteForceSolver::new().solutions(cleared(*x, *y), 1).is_empty()).or(repairs.first()).copied()
+    }
+
+    /// Tries to find a uniquely solvable puzzle with as few clues as possible by removing clues from this one.
+    /// 
+    /// Clues are greedily removed in random orders for as long as the time budget allows, only keeping removals that leave the puzzle with a unique solution. The puzzle with the fewest clues found is returned.
+    /// 
+    /// # Arguments
+    /// - `deadline`: The time budget for the search. Every removal order that is started is tried completely, so at least one order is always tried (even if that exceeds the budget).
+    /// - `rng`: The random number generator with which to shuffle the removal orders.
+    /// 
+    /// # Returns
+    /// A new [`Sudoku`] with a subset of this Sudoku's clues that has the same unique solution. If this Sudoku does not have a unique solution to begin with, it is returned as-is.
+    #[cfg(feature = "std")]
+    pub fn minimize_hard(&self, deadline: Duration, rng: &mut impl Rng) -> Sudoku {
+        let start: Instant = Instant::now();
+        let mut solver: BruteForceSolver = BruteForceSolver::new();
+        if solver.solutions(*self, 2).len() != 1 { return *self; }
+
+        // Try removal orders until we run out of time
+        let mut best: Sudoku = *self;
+        let mut clues: Vec<(usize, usize)> = (0..81).map(|i| (i % 9, i / 9)).filter(|(x, y)| self.rows[*y][*x].is_some()).collect();
+        loop {
+            clues.shuffle(rng);
+
+            // Greedily remove the clues in this order
+            let mut attempt: Sudoku = Sudoku::with_values(self.rows);
+            for (x, y) in &clues {
+                let value: Option<u8> = attempt.rows[*y][*x].take();
+                if solver.solutions(attempt, 2).len() != 1 { attempt.rows[*y][*x] = value; }
+            }
+
+            // Keep it if it's better
+            if attempt.count_empty() > best.count_empty() { best = attempt; }
+            if start.elapsed() >= deadline { return best; }
+        }
+    }
 }
 
 
@@ -51,6 +806,108 @@ mod tests {
 
 
 /***** AUXILLARY *****/
+/// Collects statistics about a solver's search.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct SolveStats {
+    /// The number of nodes (i.e., attempts) that were expanded.
+    pub nodes      : usize,
+    /// The maximum depth (i.e., number of cells assigned by the search itself) that was reached.
+    pub max_depth  : usize,
+    /// The number of times the search ran into a dead end and had to backtrack.
+    pub backtracks : usize,
+    /// The wall time the search took. Always zero without the `std` feature.
+    pub time       : Duration,
+}
+impl Display for SolveStats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        writeln!(f, "Nodes expanded : {}", self.nodes)?;
+        writeln!(f, "Maximum depth  : {}", self.max_depth)?;
+        writeln!(f, "Backtracks     : {}", self.backtracks)?;
+        writeln!(f, "Time taken     : {}ms", self.time.as_millis())
+    }
+}
+
+
+
+/// Describes what happened to an attempt in a single step of the [`BruteForceSolver`]'s search (see [`brute_force_step()`]).
+#[derive(Clone, Copy, Debug)]
+enum BruteForceStep {
+    /// The attempt was not well-formed, and has been discarded.
+    Discarded,
+    /// The attempt is a solution.
+    Finished(Sudoku),
+    /// The attempt was expanded into the given number of new attempts (zero for a dead end).
+    Expanded(Sudoku, usize),
+}
+
+
+
+/// Caches the solutions of Sudokus, such that identical puzzles only have to be solved once.
+/// 
+/// Puzzles are identified by their [compact string](Sudoku::to_compact_string()).
+#[derive(Clone, Debug, Default)]
+pub struct SolutionCache {
+    /// The solutions we've already found, keyed by the compact string of their puzzle.
+    solutions : BTreeMap<String, Sudoku>,
+}
+impl SolutionCache {
+    /// Constructor for an empty SolutionCache.
+    /// 
+    /// # Returns
+    /// A new instance of Self without any solutions in it.
+    #[inline]
+    pub fn new() -> Self { Self { solutions: BTreeMap::new() } }
+
+    /// Returns the cached solution of a puzzle, if any.
+    /// 
+    /// # Arguments
+    /// - `sudoku`: The puzzle to find the solution of.
+    /// 
+    /// # Returns
+    /// The solution of an identical puzzle, or [`None`] if we've never seen it.
+    #[inline]
+    pub fn get(&self, sudoku: &Sudoku) -> Option<Sudoku> { self.solutions.get(&sudoku.to_compact_string()).copied() }
+
+    /// Adds the solution of a puzzle to the cache.
+    /// 
+    /// # Arguments
+    /// - `sudoku`: The puzzle that was solved.
+    /// - `solution`: Its solution.
+    #[inline]
+    pub fn insert(&mut self, sudoku: &Sudoku, solution: Sudoku) { self.solutions.insert(sudoku.to_compact_string(), solution); }
+
+    /// Solves a puzzle with the given solver, unless an identical puzzle has been solved before.
+    /// 
+    /// # Arguments
+    /// - `solver`: The [`Solver`] to run on a cache miss.
+    /// - `sudoku`: The [`Sudoku`] to solve.
+    /// 
+    /// # Returns
+    /// The (cached) solution of the `sudoku`, or else the best attempt.
+    pub fn solve(&mut self, solver: &mut impl Solver, sudoku: Sudoku) -> Sudoku {
+        if let Some(solution) = self.get(&sudoku) { return solution; }
+        let solution: Sudoku = solver.run(sudoku);
+        self.insert(&sudoku, solution);
+        solution
+    }
+
+    /// Returns the number of puzzles in the cache.
+    /// 
+    /// # Returns
+    /// The number of distinct puzzles that we know the solution of.
+    #[inline]
+    pub fn len(&self) -> usize { self.solutions.len() }
+
+    /// Returns whether the cache has no puzzles in it.
+    /// 
+    /// # Returns
+    /// True if we don't know the solution of any puzzle yet, or false otherwise.
+    #[inline]
+    pub fn is_empty(&self) -> bool { self.solutions.is_empty() }
+}
+
+
+
 /// Defines what all Sudoku solvers have in common.
 pub trait Solver {
     /// Solves the given sudoku.
@@ -61,7 +918,29 @@ pub trait Solver {
     /// # Returns
     /// The solved [`Sudoku`], or else the best attempt.
     #[inline]
-    fn run(&mut self, sudoku: Sudoku) -> Sudoku { self.run_with_callback(sudoku, |_| Ok::<bool, std::convert::Infallible>(true)).unwrap().unwrap() }
+    fn run(&mut self, sudoku: Sudoku) -> Sudoku { self.run_with_callback(sudoku, |_| Ok::<bool, core::convert::Infallible>(true)).unwrap().unwrap() }
+
+    /// Turns the solver into a function, e.g., to solve puzzles in an iterator chain like `puzzles.into_iter().map(solver.as_fn())`.
+    /// 
+    /// # Returns
+    /// A closure that [runs](Solver::run()) the solver on every Sudoku it is given.
+    #[inline]
+    fn as_fn(&mut self) -> impl '_ + FnMut(Sudoku) -> Sudoku where Self: Sized { move |sudoku: Sudoku| self.run(sudoku) }
+
+    /// Preprocesses the given sudoku before the search starts, by filling in all cells that are forced (see [`Sudoku::fill_forced()`]).
+    /// 
+    /// Solvers are expected to call this at the start of [`Solver::run_with_callback()`]. It is left alone if it's not well-formed.
+    /// 
+    /// # Arguments
+    /// - `sudoku`: The [`Sudoku`] to preprocess.
+    /// 
+    /// # Returns
+    /// The preprocessed [`Sudoku`].
+    #[inline]
+    fn preprocess(&self, mut sudoku: Sudoku) -> Sudoku {
+        if sudoku.is_well_formed() { sudoku.fill_forced(); }
+        sudoku
+    }
 
     /// Solves the given sudoku, calling the given code at the end of every step.
     /// 
@@ -72,6 +951,81 @@ pub trait Solver {
     /// # Returns
     /// The solved [`Sudoku`], or else the best attempt. However, if `callback` return false at some point, `None` is returned instead.
     fn run_with_callback<E>(&mut self, sudoku: Sudoku, callback: impl FnMut(&Sudoku) -> Result<bool, E>) -> Result<Option<Sudoku>, E>;
+
+    /// Finds (up to a given number of) distinct solutions of the given sudoku.
+    /// 
+    /// By default, this exhaustively enumerates the solutions using a depth-first search, regardless of the solver's own strategy.
+    /// 
+    /// # Arguments
+    /// - `sudoku`: The [`Sudoku`] to solve.
+    /// - `cap`: The maximum number of solutions to find.
+    /// 
+    /// # Returns
+    /// A list of at most `cap` distinct, finished [`Sudoku`]s. It is empty if the `sudoku` has no solutions.
+    fn solutions(&mut self, sudoku: Sudoku, cap: usize) -> Vec<Sudoku> {
+        let mut solutions: Vec<Sudoku> = Vec::new();
+        if cap == 0 { return solutions; }
+        enumerate_solutions(sudoku, &[ [ 0; 9 ]; 9 ], |solution| {
+            solutions.push(solution);
+            solutions.len() < cap
+        });
+        solutions
+    }
+
+    /// Checks whether the given sudoku has a unique solution.
+    /// 
+    /// This stops searching as soon as a second solution is found (see [`Solver::solutions()`]), so it is cheap even for Sudokus with very many solutions.
+    /// 
+    /// # Arguments
+    /// - `sudoku`: The [`Sudoku`] to check.
+    /// 
+    /// # Returns
+    /// Whether the `sudoku` has no, a unique or multiple solutions.
+    fn uniqueness(&mut self, sudoku: Sudoku) -> Uniqueness {
+        match self.solutions(sudoku, 2).len() {
+            0 => Uniqueness::NoSolution,
+            1 => Uniqueness::Unique,
+            _ => Uniqueness::Multiple,
+        }
+    }
+
+    /// Solves the given sudoku while forbidding certain values in certain cells on top of the rules, e.g., to see what happens if a cell cannot be some value.
+    /// 
+    /// By default, this uses an exhaustive depth-first search (like [`Solver::solutions()`]), regardless of the solver's own strategy.
+    /// 
+    /// # Arguments
+    /// - `sudoku`: The [`Sudoku`] to solve.
+    /// - `forbidden`: A bitmask per cell (as rows) of the values it may not take, where bit `v` is set iff `v` is forbidden.
+    /// 
+    /// # Returns
+    /// A solved [`Sudoku`] that doesn't have any forbidden values, or [`None`] if there is no such solution.
+    fn run_with_restrictions(&mut self, sudoku: Sudoku, forbidden: &[ [ u16; 9 ]; 9 ]) -> Option<Sudoku> {
+        let mut solution: Option<Sudoku> = None;
+        enumerate_solutions(sudoku, forbidden, |s| { solution = Some(s); false });
+        solution
+    }
+
+    /// Finds the solution of the given sudoku that differs the least from some target board.
+    /// 
+    /// By default, this exhaustively enumerates the solutions (like [`Solver::solutions()`]), regardless of the solver's own strategy. Beware that this may take very long for Sudokus with many solutions.
+    /// 
+    /// # Arguments
+    /// - `sudoku`: The [`Sudoku`] to solve.
+    /// - `target`: The [`Sudoku`] to compare the solutions with. Only its filled-in cells are considered.
+    /// 
+    /// # Returns
+    /// The solution with the fewest cells that differ from those in `target` (the first one found on ties), or [`None`] if the `sudoku` has no solutions.
+    fn solve_closest_to(&mut self, sudoku: Sudoku, target: &Sudoku) -> Option<Sudoku> {
+        let distance = |s: &Sudoku| -> usize { (0..81).filter(|i| target.rows[i / 9][i % 9].is_some() && s.rows[i / 9][i % 9] != target.rows[i / 9][i % 9]).count() };
+        let mut closest: Option<(usize, Sudoku)> = None;
+        enumerate_solutions(sudoku, &[ [ 0; 9 ]; 9 ], |s| {
+            let d: usize = distance(&s);
+            if closest.map(|(best, _)| d < best).unwrap_or(true) { closest = Some((d, s)); }
+            // There's no need to look any further if it's identical
+            d > 0
+        });
+        closest.map(|(_, s)| s)
+    }
 }
 
 
@@ -96,43 +1050,510 @@ impl BruteForceSolver {
     pub fn new() -> Self {
         Self {}
     }
+
+
+
+    /// Solves the given sudoku, keeping track of how often every cell was assigned a value during the search.
+    /// 
+    /// Cells that were assigned often are the ones the solver struggled with (i.e., they are "hot").
+    /// 
+    /// # Arguments
+    /// - `sudoku`: The [`Sudoku`] to solve.
+    /// 
+    /// # Returns
+    /// A tuple of the solved [`Sudoku`] (or else the best attempt) and a 9x9 grid with the number of assignments per cell.
+    pub fn run_with_heat(&mut self, sudoku: Sudoku) -> (Sudoku, [ [ u32; 9 ]; 9 ]) {
+        let mut heat: [ [ u32; 9 ]; 9 ] = [ [ 0; 9 ]; 9 ];
+        let (solution, _): (Option<Sudoku>, SolveStats) = self.search(sudoku, |_| Ok::<bool, core::convert::Infallible>(true), |x, y| heat[y][x] += 1).unwrap();
+        (solution.unwrap(), heat)
+    }
+
+    /// Solves the given sudoku, collecting statistics about the search tree.
+    /// 
+    /// # Arguments
+    /// - `sudoku`: The [`Sudoku`] to solve.
+    /// 
+    /// # Returns
+    /// A tuple of the solved [`Sudoku`] (or else the best attempt) and the [`SolveStats`] of the search.
+    pub fn run_with_stats(&mut self, sudoku: Sudoku) -> (Sudoku, SolveStats) {
+        let (solution, stats): (Option<Sudoku>, SolveStats) = self.search(sudoku, |_| Ok::<bool, core::convert::Infallible>(true), |_, _| {}).unwrap();
+        (solution.unwrap(), stats)
+    }
+
+    /// Solves the given sudoku step-by-step, letting the caller pull the intermediate states at its own pace.
+    /// 
+    /// This inverts control compared to [`Solver::run_with_callback()`], but visits the same states in the same order.
+    /// 
+    /// # Arguments
+    /// - `sudoku`: The [`Sudoku`] to solve.
+    /// 
+    /// # Returns
+    /// An iterator that yields the Sudoku at every step of the search, ending with the solution. If the Sudoku has no solution, it simply ends after the last step.
+    pub fn steps(&self, sudoku: Sudoku) -> impl Iterator<Item = Sudoku> {
+        let mut search_space: Vec<Sudoku> = vec![ self.preprocess(sudoku) ];
+        core::iter::from_fn(move || {
+            while let Some(step) = brute_force_step(&mut search_space, |_, _| {}) {
+                // Skip attempts that are not well-formed, and stop after the first one that's finished
+                match step {
+                    BruteForceStep::Discarded            => continue,
+                    BruteForceStep::Finished(attempt)    => { search_space.clear(); return Some(attempt); },
+                    BruteForceStep::Expanded(attempt, _) => return Some(attempt),
+                }
+            }
+            None
+        })
+    }
+
+    /// Implements the actual search for [`Solver::run_with_callback()`].
+    /// 
+    /// # Arguments
+    /// - `sudoku`: The [`Sudoku`] to solve.
+    /// - `callback`: The callback to run after every step taken. Returns a boolean that indicates whether to continue (true) or not (false), and is allowed to error.
+    /// - `on_assign`: A function that is called with the coordinates of a cell every time it is assigned a value.
+    /// 
+    /// # Returns
+    /// A tuple of the solved [`Sudoku`] (or else the best attempt) and the [`SolveStats`] of the search. However, if `callback` return false at some point, `None` is returned instead of the Sudoku.
+    fn search<E>(&self, sudoku: Sudoku, mut callback: impl FnMut(&Sudoku) -> Result<bool, E>, mut on_assign: impl FnMut(usize, usize)) -> Result<(Option<Sudoku>, SolveStats), E> {
+        timed(move || {
+            // There's nothing to search for Sudokus that are already finished
+            if sudoku.count_empty() == 0 && sudoku.is_finished() { return Ok((Some(sudoku), SolveStats::default())); }
+            let sudoku: Sudoku = self.preprocess(sudoku);
+            let n_empty: usize = sudoku.count_empty();
+
+            let mut stats        : SolveStats    = SolveStats::default();
+            let mut best         : (f64, Sudoku) = (sudoku.score(), sudoku);
+            let mut search_space : Vec<Sudoku>   = vec![ sudoku ];
+            while let Some(step) = brute_force_step(&mut search_space, &mut on_assign) {
+                // Discard this attempt if it is not well-formed
+                let (attempt, n_options): (Sudoku, Option<usize>) = match step {
+                    BruteForceStep::Discarded                    => { stats.backtracks += 1; continue; },
+                    BruteForceStep::Finished(attempt)            => (attempt, None),
+                    BruteForceStep::Expanded(attempt, n_options) => (attempt, Some(n_options)),
+                };
+                // Update the best one
+                if attempt.score() > best.0 { best = (attempt.score(), attempt); }
+                // Update the statistics
+                stats.max_depth = stats.max_depth.max(n_empty - attempt.count_empty());
+                // If it's finished, we're done!
+                let Some(n_options): Option<usize> = n_options else { break; };
+
+                // Run the callback
+                stats.nodes += 1;
+                if !callback(&attempt)? { return Ok((None, stats)); };
+
+                // If we didn't add any options, this was a dead end
+                if n_options == 0 { stats.backtracks += 1; }
+            }
+
+            // Return the best attempt
+            Ok((Some(best.1), stats))
+        })
+    }
 }
 impl Solver for BruteForceSolver {
+    #[inline]
+    fn run_with_callback<E>(&mut self, sudoku: Sudoku, callback: impl FnMut(&Sudoku) -> Result<bool, E>) -> Result<Option<Sudoku>, E> {
+        Ok(self.search(sudoku, callback, |_, _| {})?.0)
+    }
+}
+
+
+
+/// Implements the same search as the [`BruteForceSolver`], but with far less memory.
+/// 
+/// Instead of keeping a copy of the Sudoku for every option still to explore, it mutates a single board and only remembers the `(x, y, value)` moves it made, undoing them when it has to backtrack. It visits the same states in the same order, and thus finds the same solution.
+/// 
+/// The search does not recurse, so it cannot overflow the stack. Its depth (the number of moves remembered) is bounded by the number of empty cells, and thus never exceeds [`BacktrackingSolver::MAX_DEPTH`]; a lower limit can be set with [`BacktrackingSolver::with_max_depth()`].
+#[derive(Clone, Debug)]
+pub struct BacktrackingSolver {
+    /// The maximum number of moves to make before giving up.
+    max_depth : usize,
+}
+impl Default for BacktrackingSolver {
+    #[inline]
+    fn default() -> Self { Self::new() }
+}
+impl BacktrackingSolver {
+    /// The maximum depth of any search, i.e., the number of cells in a Sudoku.
+    pub const MAX_DEPTH: usize = 81;
+
+
+    /// Constructor for the BacktrackingSolver.
+    /// 
+    /// # Returns
+    /// A new instance of Self.
+    #[inline]
+    pub fn new() -> Self {
+        Self { max_depth: Self::MAX_DEPTH }
+    }
+
+    /// Constructor for the BacktrackingSolver that gives up when its search gets too deep.
+    /// 
+    /// # Arguments
+    /// - `max_depth`: The maximum number of cells to fill in at once. If the search needs more, the best attempt so far is returned instead. Capped at [`BacktrackingSolver::MAX_DEPTH`].
+    /// 
+    /// # Returns
+    /// A new instance of Self.
+    #[inline]
+    pub fn with_max_depth(max_depth: usize) -> Self {
+        Self { max_depth: max_depth.min(Self::MAX_DEPTH) }
+    }
+}
+impl Solver for BacktrackingSolver {
     fn run_with_callback<E>(&mut self, sudoku: Sudoku, mut callback: impl FnMut(&Sudoku) -> Result<bool, E>) -> Result<Option<Sudoku>, E> {
-        let mut best         : (f64, Sudoku) = (sudoku.score(), sudoku);
-        let mut search_space : Vec<Sudoku>   = vec![ sudoku ];
-        while let Some(attempt) = search_space.pop() {
-            // Discard this attempt if it is not well-formed
-            if !attempt.is_well_formed() { continue; }
+        // There's nothing to search for Sudokus that are already finished (or that can never be)
+        if sudoku.count_empty() == 0 && sudoku.is_finished() { return Ok(Some(sudoku)); }
+        let mut board: Sudoku = self.preprocess(sudoku);
+        if !board.is_well_formed() { return Ok(Some(board)); }
+
+        // Try the highest valid value first, like the brute-force solver does by popping its options from a stack
+        let mut best  : (f64, Sudoku)           = (board.score(), board);
+        let mut moves : Vec<(usize, usize, u8)> = vec![];
+        loop {
             // Update the best one
-            if attempt.score() > best.0 { best.1 = attempt; }
-            // If it's finished, we're done!
-            if attempt.is_finished() { break; }
+            if board.score() > best.0 { best = (board.score(), board); }
+
+            // Find the first empty cell; if there is none, we're done!
+            let Some((x, y)): Option<(usize, usize)> = (0..81).map(|i| (i % 9, i / 9)).find(|(x, y)| board.rows[*y][*x].is_none()) else { return Ok(Some(board)); };
 
             // Run the callback
-            if !callback(&attempt)? { return Ok(None); };
-
-            // Find the first empty cell
-            'empty_cell: for y in 0..9 {
-                for x in 0..9 {
-                    // Skip if not None
-                    if attempt.rows[y][x].is_some() { continue; }
-
-                    // Iterate over the possibilities
-                    for v in 1..=9 {
-                        // Check if valid
-                        if !attempt.is_cell_valid(x, y, v) { continue; }
-
-                        // Alright add the possibility
-                        let mut next_attempt: Sudoku = attempt;
-                        next_attempt.rows[y][x] = Some(v);
-                        search_space.push(next_attempt);
+            if !callback(&board)? { return Ok(None); }
+
+            // Assign the highest candidate to the cell (the brute-force solver also considers the others, but discards them once it finds they're not well-formed)
+            if moves.len() >= self.max_depth { return Ok(Some(best.1)); }
+            if let Some(v) = board.candidates(x, y).pop() {
+                board.rows[y][x] = Some(v);
+                moves.push((x, y, v));
+                continue;
+            }
+
+            // Otherwise, undo moves until we find one that we can replace with a lower value
+            loop {
+                let Some((x, y, v)): Option<(usize, usize, u8)> = moves.pop() else { return Ok(Some(best.1)); };
+                board.rows[y][x] = None;
+                if let Some(v) = board.candidates(x, y).into_iter().rev().find(|w| *w < v) {
+                    board.rows[y][x] = Some(v);
+                    moves.push((x, y, v));
+                    break;
+                }
+            }
+        }
+    }
+}
+
+
+
+/// Implements a solver that narrows down the candidates of every cell before it guesses.
+/// 
+/// It keeps a [`CandidateGrid`] of per-cell candidate bitmasks, and repeatedly eliminates decided values from their peers (naked singles) and decides values that fit in only one cell of a row, column or box (hidden singles). Only once that gets stuck does it guess, on the cell with the fewest candidates (the first in row-major order on ties). This typically needs far fewer steps than the [`BruteForceSolver`].
+#[derive(Clone, Debug, Default)]
+pub struct ConstraintPropagationSolver {}
+impl ConstraintPropagationSolver {
+    /// Constructor for the ConstraintPropagationSolver.
+    /// 
+    /// # Returns
+    /// A new instance of Self.
+    #[inline]
+    pub fn new() -> Self {
+        Self {}
+    }
+
+
+
+    /// Narrows down the candidates in a grid with naked and hidden singles, for as long as that changes anything.
+    /// 
+    /// # Arguments
+    /// - `grid`: The [`CandidateGrid`] to narrow down in-place.
+    /// 
+    /// # Returns
+    /// False if a contradiction was found (i.e., a cell without candidates, or a value that fits nowhere in a unit), or true otherwise.
+    pub fn propagate(grid: &mut CandidateGrid) -> bool {
+        // The cells in every row, column and box
+        let units = |u: usize, i: usize| -> (usize, usize) {
+            match u / 9 {
+                0 => (i, u),
+                1 => (u - 9, i),
+                _ => (3 * ((u - 18) % 3) + i % 3, 3 * ((u - 18) / 3) + i / 3),
+            }
+        };
+
+        loop {
+            let mut changed: bool = false;
+
+            // Naked singles: remove decided values from their peers
+            for u in 0..27 {
+                let decided: u16 = (0..9).map(|i| units(u, i)).filter(|(x, y)| grid.rows[*y][*x].count_ones() == 1).fold(0, |mask, (x, y)| {
+                    // Two decided cells with the same value are a contradiction
+                    if mask & grid.rows[y][x] != 0 { u16::MAX } else { mask | grid.rows[y][x] }
+                });
+                if decided == u16::MAX { return false; }
+                for (x, y) in (0..9).map(|i| units(u, i)) {
+                    let cell: &mut u16 = &mut grid.rows[y][x];
+                    if cell.count_ones() > 1 && *cell & decided != 0 {
+                        *cell &= !decided;
+                        if *cell == 0 { return false; }
+                        changed = true;
                     }
+                }
+            }
 
-                    // Always break if we found an empty cell, since we only want to consider valid solutions
-                    break 'empty_cell;
+            // Hidden singles: decide values that fit in only one cell of a unit
+            for u in 0..27 {
+                for v in 1..=9 {
+                    let mut cells = (0..9).map(|i| units(u, i)).filter(|(x, y)| grid.contains(*x, *y, v));
+                    match (cells.next(), cells.next()) {
+                        (None, _)            => { return false; },
+                        (Some((x, y)), None) => if grid.rows[y][x] != 1 << v { grid.rows[y][x] = 1 << v; changed = true; },
+                        (Some(_), Some(_))   => {},
+                    }
                 }
             }
+
+            // Stop once nothing changes anymore
+            if !changed { return true; }
+        }
+    }
+}
+impl Solver for ConstraintPropagationSolver {
+    fn run_with_callback<E>(&mut self, sudoku: Sudoku, mut callback: impl FnMut(&Sudoku) -> Result<bool, E>) -> Result<Option<Sudoku>, E> {
+        // There's nothing to search for Sudokus that are already finished (or that can never be)
+        if sudoku.count_empty() == 0 && sudoku.is_finished() { return Ok(Some(sudoku)); }
+        let sudoku: Sudoku = self.preprocess(sudoku);
+        if !sudoku.is_well_formed() { return Ok(Some(sudoku)); }
+
+        // Search over the grids left after propagation, guessing in between
+        let to_sudoku = |grid: &CandidateGrid| -> Sudoku { Sudoku::from_fn(|x, y| grid.single(x, y)) };
+        let mut best         : (f64, Sudoku)      = (sudoku.score(), sudoku);
+        let mut search_space : Vec<CandidateGrid> = vec![ sudoku.to_candidates() ];
+        while let Some(mut grid) = search_space.pop() {
+            // Propagate, discarding the grid if it's contradictory
+            if !Self::propagate(&mut grid) { continue; }
+            let attempt: Sudoku = to_sudoku(&grid);
+            if attempt.score() > best.0 { best = (attempt.score(), attempt); }
+
+            // Run the callback
+            if !callback(&attempt)? { return Ok(None); }
+
+            // Find the undecided cell with the fewest candidates; if there is none, we're done!
+            let Some((x, y)): Option<(usize, usize)> = (0..81).map(|i| (i % 9, i / 9)).filter(|(x, y)| grid.rows[*y][*x].count_ones() > 1).min_by_key(|(x, y)| grid.rows[*y][*x].count_ones()) else {
+                return Ok(Some(attempt));
+            };
+
+            // Guess its candidates, trying the lowest first
+            for v in grid.candidates(x, y).into_iter().rev() {
+                let mut next: CandidateGrid = grid;
+                next.rows[y][x] = 1 << v;
+                search_space.push(next);
+            }
+        }
+
+        // Return the best attempt
+        Ok(Some(best.1))
+    }
+}
+
+
+
+/// Implements a solver that explains why a Sudoku cannot be solved.
+/// 
+/// It solves like the [`BruteForceSolver`], but if no solution exists, it also computes an infeasibility certificate: a minimal set of givens that cannot be completed on their own (see [`CertifyingSolver::explain()`]).
+#[derive(Clone, Debug, Default)]
+pub struct CertifyingSolver {
+    /// The solver that does the actual solving.
+    solver      : BruteForceSolver,
+    /// The certificate of the last Sudoku that could not be solved, if any.
+    certificate : Option<Vec<(usize, usize)>>,
+}
+impl CertifyingSolver {
+    /// Constructor for the CertifyingSolver.
+    /// 
+    /// # Returns
+    /// A new instance of Self.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            solver      : BruteForceSolver::new(),
+            certificate : None,
+        }
+    }
+
+
+
+    /// Explains why a Sudoku cannot be solved.
+    /// 
+    /// This finds a minimal conflicting set of givens, by dropping every given that is not needed to keep the remaining ones unsolvable. Removing any single cell from the result makes it solvable again.
+    /// 
+    /// # Arguments
+    /// - `sudoku`: The [`Sudoku`] to explain.
+    /// 
+    /// # Returns
+    /// The `(x, y)` coordinates of the cells in the conflicting set (in row-major order), or [`None`] if the `sudoku` has a solution.
+    pub fn explain(sudoku: &Sudoku) -> Option<Vec<(usize, usize)>> {
+        let solvable = |s: Sudoku| -> bool {
+            let mut found: bool = false;
+            enumerate_solutions(s, &[ [ 0; 9 ]; 9 ], |_| { found = true; false });
+            found
+        };
+        if solvable(*sudoku) { return None; }
+
+        // Drop every given we can do without
+        let mut core: Sudoku = *sudoku;
+        for (x, y) in (0..81).map(|i| (i % 9, i / 9)) {
+            let Some(value) = core.rows[y][x] else { continue; };
+            core.rows[y][x] = None;
+            if solvable(core) { core.rows[y][x] = Some(value); }
+        }
+        Some((0..81).map(|i| (i % 9, i / 9)).filter(|(x, y)| core.rows[*y][*x].is_some()).collect())
+    }
+
+    /// Returns the certificate of the last Sudoku that this solver [ran](Solver::run_with_callback()) on.
+    /// 
+    /// # Returns
+    /// The cells in the minimal conflicting set (see [`CertifyingSolver::explain()`]), or [`None`] if the last Sudoku could be solved (or nothing was solved yet).
+    #[inline]
+    pub fn certificate(&self) -> Option<&[ (usize, usize) ]> { self.certificate.as_deref() }
+}
+impl Solver for CertifyingSolver {
+    fn run_with_callback<E>(&mut self, sudoku: Sudoku, callback: impl FnMut(&Sudoku) -> Result<bool, E>) -> Result<Option<Sudoku>, E> {
+        let solution: Option<Sudoku> = self.solver.run_with_callback(sudoku, callback)?;
+        self.certificate = match solution {
+            Some(solution) if !solution.is_finished() => Self::explain(&sudoku),
+            _                                         => None,
+        };
+        Ok(solution)
+    }
+}
+
+
+
+/// Implements a stochastic solver that uses simulated annealing to find a solution.
+/// 
+/// It first fills every box with the digits it's missing, and then randomly swaps (non-given) cells within boxes to minimize the [number of conflicts](Sudoku::conflict_count()).
+#[cfg(feature = "std")]
+#[derive(Clone, Debug)]
+pub struct StochasticSolver {
+    /// The random number generator that drives the search.
+    rng          : StdRng,
+    /// The temperature at which we start annealing.
+    start_temp   : f64,
+    /// The factor with which the temperature is multiplied every iteration.
+    cooling      : f64,
+    /// The number of iterations without improvement after which we reset the temperature.
+    reheat_after : usize,
+    /// The maximum number of iterations before we give up.
+    max_iters    : usize,
+}
+
+#[cfg(feature = "std")]
+impl Default for StochasticSolver {
+    #[inline]
+    fn default() -> Self { Self::new() }
+}
+#[cfg(feature = "std")]
+impl StochasticSolver {
+    /// Constructor for the StochasticSolver that is seeded from the system's entropy.
+    /// 
+    /// # Returns
+    /// A new instance of Self.
+    #[inline]
+    pub fn new() -> Self { Self::with_rng(StdRng::from_entropy()) }
+
+    /// Constructor for the StochasticSolver that uses a fixed seed, making its runs reproducible.
+    /// 
+    /// # Arguments
+    /// - `seed`: The seed for the random number generator.
+    /// 
+    /// # Returns
+    /// A new instance of Self.
+    #[inline]
+    pub fn with_seed(seed: u64) -> Self { Self::with_rng(StdRng::seed_from_u64(seed)) }
+
+    /// Constructor for the StochasticSolver that uses the given random number generator.
+    /// 
+    /// # Arguments
+    /// - `rng`: The [`StdRng`] to drive the search with.
+    /// 
+    /// # Returns
+    /// A new instance of Self.
+    #[inline]
+    fn with_rng(rng: StdRng) -> Self {
+        Self {
+            rng,
+            start_temp   : 0.5,
+            cooling      : 0.999,
+            reheat_after : 2_000,
+            max_iters    : 1_000_000,
+        }
+    }
+}
+#[cfg(feature = "std")]
+impl Solver for StochasticSolver {
+    fn run_with_callback<E>(&mut self, sudoku: Sudoku, mut callback: impl FnMut(&Sudoku) -> Result<bool, E>) -> Result<Option<Sudoku>, E> {
+        // There's no hope for Sudokus that are already broken, and no work for those that are already finished
+        if !sudoku.is_well_formed() { return Ok(Some(sudoku)); }
+        if sudoku.count_empty() == 0 && sudoku.is_finished() { return Ok(Some(sudoku)); }
+        let sudoku: Sudoku = self.preprocess(sudoku);
+
+        // Fill every box with the digits it's missing, remembering which cells we may swap
+        let mut attempt : Sudoku                   = sudoku;
+        let mut free    : Vec<Vec<(usize, usize)>> = Vec::with_capacity(9);
+        for b in 0..9 {
+            let cells: Vec<(usize, usize)> = (0..9).map(|i| (3 * (b % 3) + i % 3, 3 * (b / 3) + i / 3)).collect();
+            let mut missing: Vec<u8> = (1..=9).filter(|v| !cells.iter().any(|(x, y)| sudoku.rows[*y][*x] == Some(*v))).collect();
+            missing.shuffle(&mut self.rng);
+
+            // Assign them to the empty cells
+            let empty: Vec<(usize, usize)> = cells.into_iter().filter(|(x, y)| sudoku.rows[*y][*x].is_none()).collect();
+            for ((x, y), v) in empty.iter().zip(missing) {
+                attempt.rows[*y][*x] = Some(v);
+            }
+            if empty.len() >= 2 { free.push(empty); }
+        }
+
+        // Now anneal until there are no conflicts left
+        let mut cost  : usize            = attempt.conflict_count();
+        let mut best  : (usize, Sudoku)  = (cost, attempt);
+        let mut temp  : f64              = self.start_temp;
+        let mut stale : usize            = 0;
+        for _ in 0..self.max_iters {
+            // Stop if we're done (or if there's nothing left to swap)
+            if cost == 0 || free.is_empty() { break; }
+
+            // Run the callback
+            if !callback(&attempt)? { return Ok(None); }
+
+            // Swap two random cells within a random box
+            let cells: &[(usize, usize)] = free.choose(&mut self.rng).unwrap();
+            let i: usize = self.rng.gen_range(0..cells.len());
+            let mut j: usize = self.rng.gen_range(0..cells.len() - 1);
+            if j >= i { j += 1; }
+            let (x1, y1): (usize, usize) = cells[i];
+            let (x2, y2): (usize, usize) = cells[j];
+            let mut next_attempt: Sudoku = attempt;
+            next_attempt.rows[y1][x1] = attempt.rows[y2][x2];
+            next_attempt.rows[y2][x2] = attempt.rows[y1][x1];
+
+            // Accept it if it's better, or with some probability depending on the temperature if it's worse
+            let next_cost: usize = next_attempt.conflict_count();
+            let delta: f64 = next_cost as f64 - cost as f64;
+            if delta <= 0.0 || self.rng.gen::<f64>() < (-delta / temp).exp() {
+                attempt = next_attempt;
+                cost = next_cost;
+            }
+
+            // Update the best one, reheating if we got stuck
+            if cost < best.0 {
+                best = (cost, attempt);
+                stale = 0;
+            } else {
+                stale += 1;
+            }
+            if stale >= self.reheat_after {
+                temp = self.start_temp;
+                stale = 0;
+            } else {
+                temp *= self.cooling;
+            }
         }
 
         // Return the best attempt
@@ -4,7 +4,7 @@
 //  Created:
 //    10 Aug 2023, 23:02:38
 //  Last edited:
-//    11 Aug 2023, 15:55:54
+//    16 Oct 2026, 12:26:29
 //  Auto updated?
 //    Yes
 // 
@@ -12,11 +12,16 @@
 //!   Defines how we represent a Sudoku.
 // 
 
-use std::error::Error;
+use alloc::string::String;
+use core::error::Error;
+use core::fmt::{Display, Formatter, Result as FResult};
+use core::str::FromStr;
+#[cfg(feature = "std")]
 use std::ffi::OsStr;
-use std::fmt::{Display, Formatter, Result as FResult};
-use std::str::FromStr;
 
+#[cfg(feature = "std")]
+use console::Color;
+#[cfg(feature = "std")]
 use enum_debug::EnumDebug;
 
 
@@ -38,13 +43,206 @@ impl Display for FileTypeParseError {
 }
 impl Error for FileTypeParseError {}
 
+/// Describes what can go wrong when parsing [`Colour`]s.
+#[derive(Debug)]
+pub enum ColourParseError {
+    /// Unknown colour given.
+    Unknown { raw: String },
+}
+impl Display for ColourParseError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        use ColourParseError::*;
+        match self {
+            Unknown { raw } => write!(f, "Unknown colour '{raw}' (expected one of 'black', 'red', 'green', 'yellow', 'blue', 'magenta', 'cyan' or 'white')"),
+        }
+    }
+}
+impl Error for ColourParseError {}
+
 
 
 
 
 /***** LIBRARY *****/
+/// Defines the order in which cells are visited when (de)serializing a Sudoku as a flat list of cells.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "std", derive(EnumDebug))]
+pub enum CellOrder {
+    /// Visits the cells row by row, left-to-right and top-to-bottom.
+    #[default]
+    RowMajor,
+    /// Visits the cells column by column, top-to-bottom and left-to-right.
+    ColMajor,
+    /// Visits the cells box by box (in row-major order), and the cells within each box in row-major order too.
+    BoxMajor,
+}
+impl CellOrder {
+    /// Returns the coordinates of the `i`th cell visited in this order.
+    /// 
+    /// # Arguments
+    /// - `i`: The index of the cell in the flat list. Must be smaller than 81.
+    /// 
+    /// # Returns
+    /// A tuple with the `(x, y)` coordinates of the cell in the Sudoku.
+    #[inline]
+    pub fn coords(&self, i: usize) -> (usize, usize) {
+        match self {
+            Self::RowMajor => (i % 9, i / 9),
+            Self::ColMajor => (i / 9, i % 9),
+            Self::BoxMajor => (3 * ((i / 9) % 3) + (i % 9) % 3, 3 * ((i / 9) / 3) + (i % 9) / 3),
+        }
+    }
+}
+
+
+
+/// Defines the symmetries that the clue pattern of a Sudoku may have (see [`Sudoku::symmetry_type()`](crate::sudoku::Sudoku::symmetry_type())).
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "std", derive(EnumDebug))]
+pub enum Symmetry {
+    /// The pattern is the same when rotated by 180 degrees around the centre.
+    Rotational180,
+    /// The pattern is the same when rotated by 90 degrees around the centre.
+    Rotational90,
+    /// The pattern is the same when mirrored in the horizontal axis (i.e., top-to-bottom).
+    HorizontalMirror,
+    /// The pattern is the same when mirrored in the vertical axis (i.e., left-to-right).
+    VerticalMirror,
+    /// The pattern is the same when mirrored in the main diagonal (top-left to bottom-right).
+    Diagonal,
+    /// The pattern is the same when mirrored in the anti-diagonal (top-right to bottom-left).
+    AntiDiagonal,
+}
+impl Symmetry {
+    /// All the symmetries there are.
+    pub const ALL: [ Self; 6 ] = [ Self::Rotational180, Self::Rotational90, Self::HorizontalMirror, Self::VerticalMirror, Self::Diagonal, Self::AntiDiagonal ];
+
+    /// Returns the coordinates that a cell maps to under this symmetry.
+    /// 
+    /// # Arguments
+    /// - `x`: The X-coordinate of the cell in the Sudoku.
+    /// - `y`: The Y-coordinate of the cell in the Sudoku.
+    /// 
+    /// # Returns
+    /// A tuple with the `(x, y)` coordinates of the cell it maps to.
+    #[inline]
+    pub fn apply(&self, x: usize, y: usize) -> (usize, usize) {
+        match self {
+            Self::Rotational180    => (8 - x, 8 - y),
+            Self::Rotational90     => (8 - y, x),
+            Self::HorizontalMirror => (x, 8 - y),
+            Self::VerticalMirror   => (8 - x, y),
+            Self::Diagonal         => (y, x),
+            Self::AntiDiagonal     => (8 - y, 8 - x),
+        }
+    }
+}
+
+
+
+/// Defines how many solutions a Sudoku has, as far as uniqueness is concerned (see [`Solver::uniqueness()`](crate::solvers::Solver::uniqueness())).
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "std", derive(EnumDebug))]
+pub enum Uniqueness {
+    /// The Sudoku has no solutions at all.
+    NoSolution,
+    /// The Sudoku has exactly one solution.
+    Unique,
+    /// The Sudoku has two or more solutions.
+    Multiple,
+}
+
+impl Display for Uniqueness {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        use Uniqueness::*;
+        match self {
+            NoSolution => write!(f, "no solution"),
+            Unique     => write!(f, "unique"),
+            Multiple   => write!(f, "multiple"),
+        }
+    }
+}
+
+
+
+/// Defines the named colours with which Sudokus can be printed to the terminal.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "std", derive(EnumDebug))]
+pub enum Colour {
+    /// The terminal's black (ANSI colour 0).
+    Black,
+    /// The terminal's red (ANSI colour 1).
+    Red,
+    /// The terminal's green (ANSI colour 2).
+    Green,
+    /// The terminal's yellow (ANSI colour 3).
+    Yellow,
+    /// The terminal's blue (ANSI colour 4).
+    Blue,
+    /// The terminal's magenta (ANSI colour 5).
+    Magenta,
+    /// The terminal's cyan (ANSI colour 6).
+    Cyan,
+    /// The terminal's white (ANSI colour 7).
+    White,
+}
+
+impl Display for Colour {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        use Colour::*;
+        match self {
+            Black   => write!(f, "black"),
+            Red     => write!(f, "red"),
+            Green   => write!(f, "green"),
+            Yellow  => write!(f, "yellow"),
+            Blue    => write!(f, "blue"),
+            Magenta => write!(f, "magenta"),
+            Cyan    => write!(f, "cyan"),
+            White   => write!(f, "white"),
+        }
+    }
+}
+impl FromStr for Colour {
+    type Err = ColourParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "black"   => Ok(Self::Black),
+            "red"     => Ok(Self::Red),
+            "green"   => Ok(Self::Green),
+            "yellow"  => Ok(Self::Yellow),
+            "blue"    => Ok(Self::Blue),
+            "magenta" => Ok(Self::Magenta),
+            "cyan"    => Ok(Self::Cyan),
+            "white"   => Ok(Self::White),
+
+            _ => Err(ColourParseError::Unknown { raw: s.into() }),
+        }
+    }
+}
+#[cfg(feature = "std")]
+impl From<Colour> for Color {
+    #[inline]
+    fn from(value: Colour) -> Self {
+        match value {
+            Colour::Black   => Self::Black,
+            Colour::Red     => Self::Red,
+            Colour::Green   => Self::Green,
+            Colour::Yellow  => Self::Yellow,
+            Colour::Blue    => Self::Blue,
+            Colour::Magenta => Self::Magenta,
+            Colour::Cyan    => Self::Cyan,
+            Colour::White   => Self::White,
+        }
+    }
+}
+
+
+
 /// Defines possible Sudoku file types.
-#[derive(Clone, Copy, Debug, EnumDebug, Eq, Hash, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "std", derive(EnumDebug))]
 pub enum FileType {
     // General types
     /// Load it as a direct JSON representation of the [`Sudoku`](crate::sudoku::Sudoku)-struct.
@@ -67,10 +265,26 @@ pub enum FileType {
     ///     ]
     /// }
     /// ```
+    /// 
+    /// Alternatively, the file may contain a single compact string of 81 cells (see [`Sudoku::from_str()`](crate::sudoku::Sudoku#method.from_str)):
+    /// ```json
+    /// "435269781682571493197834562826195347374682915951743628519326874248957136763418259"
+    /// ```
     Json,
+    /// A CSV file with a header row, and then one named Sudoku per row as a compact string of 81 cells (see [`Sudoku::from_str()`](crate::sudoku::Sudoku#method.from_str)).
+    /// 
+    /// Names may be quoted if they contain commas. Use [`load_sudoku_csv()`](crate::utils::load_sudoku_csv()) to also load the names.
+    /// 
+    /// # Example
+    /// ```plain
+    /// name,puzzle
+    /// easy,2..1.5..3.54...71..1.2.3.8.6.28.73.4.........1.53.98.6.2.7.1.6..81...24.7..4.2..1
+    /// "hard, really",016400000200009000400000062070230100100000003003087040960000005000800007000006820
+    /// ```
+    Csv,
 
     // Specialized types
-    /// The Sudoku Puzzle (.sdk) format, which can contain Sudoku metadata (but which we will ignore).
+    /// The Sudoku Puzzle (.sdk) format, which can contain Sudoku metadata (which is ignored, unless loaded with [`load_sudoku_with_metadata()`](crate::utils::load_sudoku_with_metadata())).
     /// 
     /// See <http://www.sudocue.net/fileformats.php>.
     /// 
@@ -94,9 +308,26 @@ pub enum FileType {
     /// 7..4.2..1
     /// ```
     SudokuPuzzle,
+    /// A plain-text grid (.txt) of 9 lines with 9 cells each, where either `0` or `.` means an empty cell.
+    /// 
+    /// This is essentially the [Sudoku Puzzle](FileType::SudokuPuzzle) format without metadata.
+    /// 
+    /// # Example
+    /// ```plain
+    /// 200105003
+    /// 054000710
+    /// 010203080
+    /// 602807304
+    /// .........
+    /// 105309806
+    /// 020701060
+    /// 081000240
+    /// 700402001
+    /// ```
+    PlainGrid,
     /// The Sudoku Puzzle Progress (.sdx) format, which represents a sudoku as possible options (i.e., small numbers stuff).
     /// 
-    /// See <http://www.sudocue.net/fileformats.php>.
+    /// See <http://www.sudocue.net/fileformats.php>. Cells may be separated by whitespace or, as some tools export them, by commas.
     /// 
     /// # Example
     /// ```plain
@@ -113,6 +344,8 @@ pub enum FileType {
     SudokuPuzzleProgress,
     /// The Sudoku Puzzle Collection (.sdm) format, which holds multiple sudokus in one file.
     /// 
+    /// Usually there is one Sudoku per line, but Sudokus may also be separated by semicolons and/or whitespace.
+    /// 
     /// See <http://www.sudocue.net/fileformats.php>.
     /// 
     /// # Example
@@ -129,7 +362,7 @@ pub enum FileType {
     SudokuPuzzleCollection,
     /// The Simple Sudoku (.ss) format, which can parse both the new- and old-style format.
     /// 
-    /// This is the older version of the two.
+    /// The style is detected from the contents: files with `|` column separators (or a `-----------` separator as fourth line) are new-style, and others old-style.
     /// 
     /// See <http://www.sudocue.net/fileformats.php>.
     /// 
@@ -176,29 +409,108 @@ pub enum FileType {
     /// X4X5X8X7X
     /// ```
     SimpleSudokuOld,
+    /// The XML format (.opensudoku) exported by the [OpenSudoku](https://opensudoku.moire.org/) Android app, which holds a collection of Sudokus.
+    /// 
+    /// Every `<game>`-element holds a single Sudoku as a compact string of 81 cells (see [`Sudoku::from_str()`](crate::sudoku::Sudoku#method.from_str)). Other elements are ignored.
+    /// 
+    /// # Example
+    /// ```xml
+    /// <?xml version="1.0" encoding="UTF-8"?>
+    /// <opensudoku>
+    ///   <name>Example</name>
+    ///   <game data="016400000200009000400000062070230100100000003003087040960000005000800007000006820" />
+    ///   <game data="049008605003007000000000030000400800060815020001009000010000000000600400804500390" />
+    /// </opensudoku>
+    /// ```
+    OpenSudoku,
+    /// A Markdown file (.md) with [Plain Grid](FileType::PlainGrid)s in fenced code blocks, e.g., a notebook of puzzles.
+    /// 
+    /// Every code block without a language (or marked as `plain`, `text` or `txt`) holds a single Sudoku; the rest of the file is ignored.
+    /// 
+    /// # Example
+    /// ````markdown
+    /// # My puzzles
+    /// An easy one:
+    /// ```plain
+    /// 2..1.5..3
+    /// .54...71.
+    /// .1.2.3.8.
+    /// 6.28.73.4
+    /// .........
+    /// 1.53.98.6
+    /// .2.7.1.6.
+    /// .81...24.
+    /// 7..4.2..1
+    /// ```
+    /// ````
+    Markdown,
 }
 impl FileType {
+    /// Maps every extension that [`FileType::from_ext()`] recognizes to the type it is loaded as.
+    /// 
+    /// | Extension             | Type                                                         |
+    /// |-----------------------|--------------------------------------------------------------|
+    /// | `.json`               | [JSON](FileType::Json)                                       |
+    /// | `.csv`                | [CSV](FileType::Csv)                                         |
+    /// | `.sdk`, `.sudoku`     | [Sudoku Puzzle](FileType::SudokuPuzzle)                      |
+    /// | `.txt`, `.text`       | [Plain Grid](FileType::PlainGrid)                            |
+    /// | `.sdx`                | [Sudoku Puzzle Progress](FileType::SudokuPuzzleProgress)     |
+    /// | `.sdm`                | [Sudoku Puzzle Collection](FileType::SudokuPuzzleCollection) |
+    /// | `.ss`                 | [Simple Sudoku](FileType::SimpleSudoku)                      |
+    /// | `.opensudoku`, `.xml` | [OpenSudoku](FileType::OpenSudoku)                           |
+    /// | `.md`, `.markdown`    | [Markdown](FileType::Markdown)                               |
+    /// 
+    /// Since a Plain Grid is a Sudoku Puzzle without metadata, `.sudoku` is read as the latter so that any metadata is accepted too.
+    pub const EXTENSIONS: [ (&'static str, Self); 13 ] = [
+        ("json", Self::Json),
+        ("csv", Self::Csv),
+        ("sdk", Self::SudokuPuzzle),
+        ("sudoku", Self::SudokuPuzzle),
+        ("txt", Self::PlainGrid),
+        ("text", Self::PlainGrid),
+        ("sdx", Self::SudokuPuzzleProgress),
+        ("sdm", Self::SudokuPuzzleCollection),
+        ("ss", Self::SimpleSudoku),
+        ("opensudoku", Self::OpenSudoku),
+        ("xml", Self::OpenSudoku),
+        ("md", Self::Markdown),
+        ("markdown", Self::Markdown),
+    ];
+
     /// Attempts to deduce the file type from the given extension.
     /// 
+    /// See [`FileType::EXTENSIONS`] for the full mapping. Extensions are matched case-insensitively.
+    /// 
     /// # Arguments
     /// - `ext`: The [`Extension`] in a filepath to analyse.
     /// 
     /// # Returns
     /// The corresponding FileType if it was known, or [`None`] otherwise.
+    #[cfg(feature = "std")]
     pub fn from_ext(ext: &OsStr) -> Option<Self> {
-        // Check if it is a valid extension
-        if ext == OsStr::new("json") {
-            Some(Self::Json)
-        } else if ext == OsStr::new("sdk") {
-            Some(Self::SudokuPuzzle)
-        } else if ext == OsStr::new("sdx") {
-            Some(Self::SudokuPuzzleProgress)
-        } else if ext == OsStr::new("sdm") {
-            Some(Self::SudokuPuzzleCollection)
-        } else if ext == OsStr::new("ss") {
-            Some(Self::SimpleSudoku)
-        } else {
-            None
+        let ext: &str = ext.to_str()?;
+        Self::EXTENSIONS.iter().find(|(known, _)| known.eq_ignore_ascii_case(ext)).map(|(_, ftype)| *ftype)
+    }
+
+    /// Returns the extension that files of this type are typically saved with.
+    /// 
+    /// # Returns
+    /// The extension (without the dot), such that [`FileType::from_ext()`] maps it to a type that can read this one.
+    pub fn extension(&self) -> &'static str {
+        use FileType::*;
+        match self {
+            Json => "json",
+            Csv  => "csv",
+
+            SudokuPuzzle           => "sdk",
+            PlainGrid              => "txt",
+            SudokuPuzzleProgress   => "sdx",
+            SudokuPuzzleCollection => "sdm",
+            SimpleSudoku           => "ss",
+            SimpleSudokuNew        => "ss",
+            SimpleSudokuOld        => "ss",
+            OpenSudoku             => "opensudoku",
+            Markdown               => "md",
         }
     }
 }
@@ -208,13 +520,17 @@ impl Display for FileType {
         use FileType::*;
         match self {
             Json   => write!(f, "JSON"),
+            Csv    => write!(f, "CSV"),
 
             SudokuPuzzle           => write!(f, "Sudoku Puzzle"),
+            PlainGrid              => write!(f, "Plain Grid"),
             SudokuPuzzleProgress   => write!(f, "Sudoku Puzzle Progress"),
             SudokuPuzzleCollection => write!(f, "Sudoku Puzzle Collection"),
             SimpleSudoku           => write!(f, "Simple Sudoku"),
             SimpleSudokuNew        => write!(f, "Simple Sudoku (new style)"),
             SimpleSudokuOld        => write!(f, "Simple Sudoku (old style)"),
+            OpenSudoku             => write!(f, "OpenSudoku"),
+            Markdown               => write!(f, "Markdown"),
         }
     }
 }
@@ -224,15 +540,42 @@ impl FromStr for FileType {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s.to_lowercase().as_str() {
             "json" => Ok(Self::Json),
+            "csv"  => Ok(Self::Csv),
 
             "sudoku_puzzle"            | "sdk" => Ok(Self::SudokuPuzzle),
+            "plain_grid"               | "txt" => Ok(Self::PlainGrid),
             "sudoku_puzzle_progress"   | "sdx" => Ok(Self::SudokuPuzzleProgress),
             "sudoku_puzzle_collection" | "sdm" => Ok(Self::SudokuPuzzleCollection),
             "simple_sudoku" | "ss"             => Ok(Self::SimpleSudoku),
             "simple_sudoku_new" | "ss_new"     => Ok(Self::SimpleSudokuNew),
             "simple_sudoku_old" | "ss_old"     => Ok(Self::SimpleSudokuOld),
+            "open_sudoku" | "opensudoku" | "xml" => Ok(Self::OpenSudoku),
+            "markdown" | "md"                    => Ok(Self::Markdown),
 
             _ => Err(FileTypeParseError::Unknown { raw: s.into() }),
         }
     }
 }
+
+
+
+/// Defines the metadata that may accompany a Sudoku, as found in the [Sudoku Puzzle](FileType::SudokuPuzzle) format.
+/// 
+/// Every field is [`None`] if the file did not specify it.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct SudokuMetadata {
+    /// The author of the puzzle (`#A`).
+    pub author      : Option<String>,
+    /// A description of the puzzle (`#D`).
+    pub description : Option<String>,
+    /// A comment on the puzzle (`#C`).
+    pub comment     : Option<String>,
+    /// The date the puzzle was published (`#B`), as written in the file.
+    pub date        : Option<String>,
+    /// The source of the puzzle (`#S`), e.g., the program that generated it.
+    pub source      : Option<String>,
+    /// The difficulty level of the puzzle (`#L`), as written in the file.
+    pub level       : Option<String>,
+    /// A URL with more information on the puzzle (`#U`).
+    pub url         : Option<String>,
+}
@@ -4,7 +4,7 @@
 //  Created:
 //    11 Aug 2023, 11:42:21
 //  Last edited:
-//    11 Aug 2023, 23:36:52
+//    16 Oct 2026, 12:42:41
 //  Auto updated?
 //    Yes
 // 
@@ -12,18 +12,37 @@
 //!   Defines a Sudoku and its behaviour.
 // 
 
-use std::error::Error;
-use std::fmt::{Display, Formatter, Result as FResult};
+use alloc::format;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::error::Error;
+use core::fmt::{Display, Formatter, Result as FResult};
+use core::str::FromStr;
 
+#[cfg(feature = "std")]
 use console::Style;
+#[cfg(feature = "std")]
 use enum_debug::EnumDebug;
+#[cfg(feature = "std")]
+use rand::Rng;
+#[cfg(feature = "std")]
+use rand::seq::SliceRandom as _;
+#[cfg(feature = "std")]
 use ratatui::widgets::{Row, Table};
 use serde::{Deserialize, Serialize};
 
+use crate::spec::{CellOrder, Symmetry};
+
 
 /***** TESTS *****/
 #[cfg(test)]
 mod tests {
+    use rand::SeedableRng as _;
+    use rand::rngs::StdRng;
+
+    use crate::solvers::{BruteForceSolver, Solver as _};
+    use crate::spec::Colour;
     use crate::utils::{load_sudoku, PrettyError as _};
     use super::*;
 
@@ -67,6 +86,760 @@ mod tests {
             assert_eq!(box_err.finished(), Err(InvalidReason::BoxConflict { cell: (5, 2), conflict: (3, 1) }));
         }
     }
+
+    #[test]
+    fn test_sudoku_box_conflicts() {
+        // Place a duplicate at every pair of positions in every box
+        for b in 0..9 {
+            let cells: Vec<(usize, usize)> = (0..9).map(|i| (3 * (b % 3) + i % 3, 3 * (b / 3) + i / 3)).collect();
+            for (i, (x1, y1)) in cells.iter().copied().enumerate() {
+                for (x2, y2) in cells[i + 1..].iter().copied() {
+                    let mut sudoku: Sudoku = Sudoku::empty();
+                    sudoku.rows[y1][x1] = Some(5);
+
+                    // Checking the later cell reports the earlier one as its conflicting peer
+                    let expected: InvalidReason = if y1 == y2 {
+                        InvalidReason::RowConflict { cell: (x2, y2), conflict: (x1, y1) }
+                    } else if x1 == x2 {
+                        InvalidReason::ColConflict { cell: (x2, y2), conflict: (x1, y1) }
+                    } else {
+                        InvalidReason::BoxConflict { cell: (x2, y2), conflict: (x1, y1) }
+                    };
+                    assert_eq!(sudoku.cell_valid(x2, y2, 5), Err(expected), "Wrong conflict for cells ({x1},{y1}) and ({x2},{y2})");
+                    assert_eq!(sudoku.cell_valid(x2, y2, 4), Ok(()));
+
+                    // The same when checking the whole Sudoku
+                    sudoku.rows[y2][x2] = Some(5);
+                    assert_eq!(sudoku.well_formed(), Err(expected), "Wrong conflict for cells ({x1},{y1}) and ({x2},{y2})");
+                    assert_eq!(sudoku.box_valid(b), Err(InvalidReason::BoxConflict { cell: (x2, y2), conflict: (x1, y1) }));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_sudoku_unit_validity() {
+        // A correct Sudoku has only valid units
+        let correct: Sudoku = load_sudoku("./tests/correct.json").unwrap_or_else(|err| panic!("Failed to load correct Sudoku: {}", err.pretty())).swap_remove(0);
+        for i in 0..9 {
+            assert_eq!(correct.row_valid(i), Ok(()));
+            assert_eq!(correct.col_valid(i), Ok(()));
+            assert_eq!(correct.box_valid(i), Ok(()));
+        }
+
+        // Only the units with a duplicate are invalid
+        let mut sudoku: Sudoku = Sudoku::empty();
+        sudoku.rows[0][2] = Some(5);
+        sudoku.rows[8][2] = Some(5);
+        for i in 0..9 {
+            assert_eq!(sudoku.row_valid(i), Ok(()));
+            if i == 2 { assert_eq!(sudoku.col_valid(i), Err(InvalidReason::ColConflict { cell: (2, 8), conflict: (2, 0) })); }
+            else { assert_eq!(sudoku.col_valid(i), Ok(())); }
+            assert_eq!(sudoku.box_valid(i), Ok(()));
+        }
+        sudoku.rows[8][2] = None;
+        sudoku.rows[1][0] = Some(5);
+        assert_eq!(sudoku.box_valid(0), Err(InvalidReason::BoxConflict { cell: (0, 1), conflict: (2, 0) }));
+        sudoku.rows[1][0] = None;
+        sudoku.rows[0][7] = Some(5);
+        assert_eq!(sudoku.row_valid(0), Err(InvalidReason::RowConflict { cell: (7, 0), conflict: (2, 0) }));
+
+        // Out-of-range values (e.g., unnormalized zeroes) never conflict with each other
+        let mut zeroes: Sudoku = Sudoku::empty();
+        zeroes.rows[0][1] = Some(0);
+        zeroes.rows[0][4] = Some(0);
+        zeroes.rows[0][6] = Some(10);
+        zeroes.rows[0][8] = Some(10);
+        assert_eq!(zeroes.row_valid(0), Ok(()));
+        assert_eq!(zeroes.box_valid(1), Ok(()));
+    }
+
+    #[test]
+    fn test_sudoku_empty_full() {
+        let empty: Sudoku = load_sudoku("./tests/empty.json").unwrap_or_else(|err| panic!("Failed to load empty Sudoku: {}", err.pretty())).swap_remove(0);
+        let partial: Sudoku = load_sudoku("./tests/example.sdk").unwrap_or_else(|err| panic!("Failed to load partial Sudoku: {}", err.pretty())).swap_remove(0);
+        let correct: Sudoku = load_sudoku("./tests/correct.json").unwrap_or_else(|err| panic!("Failed to load correct Sudoku: {}", err.pretty())).swap_remove(0);
+
+        // Check the emptiness
+        assert!(empty.is_empty());
+        assert!(!partial.is_empty());
+        assert!(!correct.is_empty());
+
+        // Check the fullness
+        assert!(!empty.is_full());
+        assert!(!partial.is_full());
+        assert!(correct.is_full());
+    }
+
+    #[test]
+    fn test_sudoku_obvious_contradiction() {
+        let partial: Sudoku = load_sudoku("./tests/example.sdk").unwrap_or_else(|err| panic!("Failed to load partial Sudoku: {}", err.pretty())).swap_remove(0);
+        assert!(!Sudoku::empty().has_obvious_contradiction());
+        assert!(!partial.has_obvious_contradiction());
+
+        // Eliminate all candidates of the top-left cell through its row, column and box
+        let mut sudoku: Sudoku = Sudoku::empty();
+        for (i, v) in (1..=3).enumerate() { sudoku.rows[0][3 + i] = Some(v); }
+        for (i, v) in (4..=6).enumerate() { sudoku.rows[3 + i][0] = Some(v); }
+        sudoku.rows[1][1] = Some(7);
+        sudoku.rows[1][2] = Some(8);
+        sudoku.rows[2][1] = Some(9);
+        println!("\n{sudoku}");
+        assert!(sudoku.is_well_formed());
+        assert!(sudoku.has_obvious_contradiction());
+        assert!(sudoku.candidates(0, 0).is_empty());
+
+        // Values outside of the 1-9 range are ignored, instead of overflowing or masking the contradiction
+        let mut large: Sudoku = sudoku;
+        large.rows[0][8] = Some(12);
+        large.rows[8][0] = Some(20);
+        assert!(large.has_obvious_contradiction());
+        large.rows[1][2] = Some(255);
+        assert!(!large.has_obvious_contradiction());
+    }
+
+    #[test]
+    fn test_sudoku_conflict_count() {
+        // A correct Sudoku has no conflicts
+        let correct: Sudoku = load_sudoku("./tests/correct.json").unwrap_or_else(|err| panic!("Failed to load correct Sudoku: {}", err.pretty())).swap_remove(0);
+        assert_eq!(correct.conflict_count(), 0);
+
+        // One conflict in a row and one in a column
+        let mut sudoku: Sudoku = Sudoku::empty();
+        sudoku.rows[0][0] = Some(1);
+        sudoku.rows[0][8] = Some(1);
+        sudoku.rows[8][0] = Some(1);
+        println!("\n{sudoku}");
+        assert_eq!(sudoku.conflict_count(), 2);
+
+        // A pair sharing both a row and a box counts twice
+        let mut sudoku: Sudoku = Sudoku::empty();
+        sudoku.rows[4][3] = Some(7);
+        sudoku.rows[4][5] = Some(7);
+        println!("{sudoku}");
+        assert_eq!(sudoku.conflict_count(), 2);
+    }
+
+    #[test]
+    fn test_sudoku_givens() {
+        let mut sudoku: Sudoku = load_sudoku("./tests/example.sdk").unwrap_or_else(|err| panic!("Failed to load Sudoku: {}", err.pretty())).swap_remove(0);
+        assert_eq!(sudoku.givens(), None);
+        sudoku.lock_givens();
+        println!("\n{}", sudoku.coloured());
+
+        // Givens may not be overwritten, but others can
+        assert!(sudoku.is_given(0, 0));
+        assert_eq!(sudoku.set(0, 0, Some(4)), Err(InvalidReason::LockedCell { cell: (0, 0) }));
+        assert_eq!(sudoku.rows[0][0], Some(2));
+        assert!(!sudoku.is_given(1, 0));
+        assert_eq!(sudoku.set(1, 0, Some(6)), Ok(()));
+        assert_eq!(sudoku.set(1, 0, None), Ok(()));
+
+        // The solver cannot place anything else in a locked cell either
+        assert!(sudoku.is_cell_valid(0, 0, 2));
+        assert_eq!(sudoku.cell_valid(0, 0, 4), Err(InvalidReason::LockedCell { cell: (0, 0) }));
+        let solved: Sudoku = BruteForceSolver::new().run(sudoku);
+        assert!(solved.is_finished());
+        assert_eq!(solved.givens(), sudoku.givens());
+        for y in 0..9 {
+            for x in 0..9 {
+                if sudoku.is_given(x, y) { assert_eq!(solved.rows[y][x], sudoku.rows[y][x]); }
+            }
+        }
+
+        // Locking does not affect equality
+        let mut unlocked: Sudoku = sudoku;
+        unlocked.givens = None;
+        assert_eq!(unlocked, sudoku);
+        assert_ne!(unlocked.givens(), sudoku.givens());
+    }
+
+    #[test]
+    fn test_sudoku_place_if_valid() {
+        let mut sudoku: Sudoku = load_sudoku("./tests/example.sdk").unwrap_or_else(|err| panic!("Failed to load Sudoku: {}", err.pretty())).swap_remove(0);
+        let solution: Sudoku = BruteForceSolver::new().run(sudoku);
+        let (x, y): (usize, usize) = (0..81).map(|i| (i % 9, i / 9)).find(|(x, y)| sudoku.rows[*y][*x].is_none()).unwrap();
+
+        // Values that conflict with a peer are refused, leaving the board as-is
+        let original: Sudoku = sudoku;
+        let conflict: u8 = sudoku.rows[y].iter().flatten().copied().next().unwrap();
+        assert!(!sudoku.place_if_valid(x, y, conflict));
+        assert_eq!(sudoku, original);
+
+        // Legal values are placed
+        let value: u8 = solution.rows[y][x].unwrap();
+        assert!(sudoku.place_if_valid(x, y, value));
+        assert_eq!(sudoku.rows[y][x], Some(value));
+
+        // Locked givens are never overwritten, not even by the same value
+        sudoku.lock_givens();
+        let (gx, gy): (usize, usize) = (0..81).map(|i| (i % 9, i / 9)).find(|(x, y)| sudoku.is_given(*x, *y)).unwrap();
+        let given: u8 = sudoku.rows[gy][gx].unwrap();
+        assert!(!sudoku.place_if_valid(gx, gy, given));
+    }
+
+    #[test]
+    fn test_sudoku_with_hints() {
+        let puzzle: Sudoku = load_sudoku("./tests/example.sdk").unwrap_or_else(|err| panic!("Failed to load Sudoku: {}", err.pretty())).swap_remove(0);
+        let solution: Sudoku = BruteForceSolver::new().run(puzzle);
+
+        // The first empty cells are filled-in
+        let (hint, given): (Sudoku, usize) = puzzle.with_hints(&solution, 2);
+        assert_eq!(given, 2);
+        assert_eq!(hint.count_empty(), puzzle.count_empty() - 2);
+        assert_eq!((hint.rows[0][1], hint.rows[0][2]), (solution.rows[0][1], solution.rows[0][2]));
+
+        // Asking for too many hints on a near-complete board only fills the blanks there are
+        let mut near: Sudoku = solution;
+        near.rows[4][4] = None;
+        near.rows[8][0] = None;
+        let (hint, given): (Sudoku, usize) = near.with_hints(&solution, 100);
+        assert_eq!(given, 2);
+        assert_eq!(hint, solution);
+    }
+
+    #[test]
+    fn test_sudoku_clue_mask() {
+        let mut sudoku: Sudoku = load_sudoku("./tests/example.sdk").unwrap_or_else(|err| panic!("Failed to load Sudoku: {}", err.pretty())).swap_remove(0);
+        let solved: Sudoku = BruteForceSolver::new().run(sudoku);
+
+        // A puzzle is its own mask, but the solution isn't
+        assert_eq!(sudoku.clue_mask(), sudoku);
+        assert_ne!(solved.clue_mask().rows, sudoku.rows);
+
+        // Once givens are locked, the solution remembers its clues
+        sudoku.lock_givens();
+        let solved: Sudoku = BruteForceSolver::new().run(sudoku);
+        assert!(solved.is_finished());
+        assert_eq!(solved.clue_mask().rows, sudoku.rows);
+    }
+
+    #[test]
+    fn test_sudoku_compact_string() {
+        let correct: Sudoku = load_sudoku("./tests/correct.json").unwrap_or_else(|err| panic!("Failed to load correct Sudoku: {}", err.pretty())).swap_remove(0);
+        assert_eq!(correct.to_compact_string(), "435269781682571493197834562826195347374682915951743628519326874248957136763418259");
+        assert_eq!(Sudoku::from_str(&correct.to_compact_string()), Ok(correct));
+
+        // Both empty cell markers are accepted
+        let mut sudoku: Sudoku = Sudoku::empty();
+        sudoku.rows[0][1] = Some(5);
+        assert_eq!(sudoku.to_compact_string(), format!("05{}", "0".repeat(79)));
+        assert_eq!(Sudoku::from_str(&format!(".5{}", ".".repeat(79))), Ok(sudoku));
+
+        // Wrong strings are rejected
+        assert_eq!(Sudoku::from_str("123"), Err(CompactParseError::IncorrectLength { got: 3 }));
+        assert_eq!(Sudoku::from_str(&format!("x{}", "0".repeat(80))), Err(CompactParseError::IllegalCellChar { cell: 1, got: 'x' }));
+    }
+
+    #[test]
+    fn test_sudoku_compact_display() {
+        let sudoku: Sudoku = load_sudoku("./tests/example.sdk").unwrap_or_else(|err| panic!("Failed to load Sudoku: {}", err.pretty())).swap_remove(0);
+        let line: String = sudoku.compact().to_string();
+        assert_eq!(line.len(), 81);
+        assert_eq!(&line[..18], "2..1.5..3.54...71.");
+        assert_eq!(line, sudoku.to_compact_string().replace('0', "."));
+        assert_eq!(Sudoku::from_str(&line), Ok(sudoku));
+    }
+
+    #[test]
+    fn test_sudoku_masked_partial() {
+        let puzzle: Sudoku = load_sudoku("./tests/example.sdk").unwrap_or_else(|err| panic!("Failed to load Sudoku: {}", err.pretty())).swap_remove(0);
+        let solved: Sudoku = BruteForceSolver::new().run(puzzle);
+
+        // Make a partial solution by emptying some solved cells
+        let mut partial: Sudoku = solved;
+        partial.rows[0][1] = None;
+        partial.rows[4][4] = None;
+        partial.rows[8][7] = None;
+        let render: String = partial.masked(&puzzle).to_string();
+        println!("{render}");
+
+        // The empty cells are shown as dots, unlike the solved ones
+        assert_eq!(render.matches('·').count(), 3);
+        assert!(!solved.masked(&puzzle).to_string().contains('·'));
+        assert_eq!(puzzle.masked(&puzzle).to_string().matches('·').count(), puzzle.count_empty());
+    }
+
+    #[test]
+    fn test_dlx_matrix() {
+        // A solved board leaves exactly one row per cell, which together form an exact cover
+        let correct: Sudoku = load_sudoku("./tests/correct.json").unwrap_or_else(|err| panic!("Failed to load Sudoku: {}", err.pretty())).swap_remove(0);
+        let matrix: Vec<Vec<bool>> = dlx_matrix(&correct);
+        assert_eq!(matrix.len(), 81);
+        for col in 0..324 { assert_eq!(matrix.iter().filter(|row| row[col]).count(), 1, "Column {col} is not covered exactly once"); }
+
+        // A puzzle keeps a row for every given and every candidate
+        let puzzle: Sudoku = load_sudoku("./tests/example.sdk").unwrap_or_else(|err| panic!("Failed to load Sudoku: {}", err.pretty())).swap_remove(0);
+        let matrix: Vec<Vec<bool>> = dlx_matrix(&puzzle);
+        assert_eq!(matrix.len(), 81 - puzzle.count_empty() + (0..81).map(|i| if puzzle.rows[i / 9][i % 9].is_none() { puzzle.candidates(i % 9, i / 9).len() } else { 0 }).sum::<usize>());
+        assert!(matrix.iter().all(|row| row.len() == 324 && row.iter().filter(|c| **c).count() == 4));
+
+        // An empty board has the full matrix
+        assert_eq!(dlx_matrix(&Sudoku::empty()).len(), 729);
+    }
+
+    #[test]
+    fn test_sudoku_to_candidates() {
+        let sudoku: Sudoku = load_sudoku("./tests/example.sdk").unwrap_or_else(|err| panic!("Failed to load Sudoku: {}", err.pretty())).swap_remove(0);
+        let grid: CandidateGrid = sudoku.to_candidates();
+        for y in 0..9 {
+            for x in 0..9 {
+                match sudoku.rows[y][x] {
+                    // Filled cells are singletons
+                    Some(v) => {
+                        assert_eq!(grid.candidates(x, y), vec![ v ]);
+                        assert_eq!(grid.single(x, y), Some(v));
+                    },
+                    // Empty cells get their legal candidates
+                    None => assert_eq!(grid.candidates(x, y), sudoku.candidates(x, y)),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_sudoku_masked_colours() {
+        let puzzle: Sudoku = load_sudoku("./tests/example.sdk").unwrap_or_else(|err| panic!("Failed to load Sudoku: {}", err.pretty())).swap_remove(0);
+        let solved: Sudoku = BruteForceSolver::new().run(puzzle);
+
+        // By default, givens are blue and solved cells are just bold
+        let render: String = solved.masked(&puzzle).force_styling(true).to_string();
+        assert!(render.contains("\x1b[34m"));
+        assert!(!render.contains("\x1b[31m") && !render.contains("\x1b[32m"));
+
+        // But they can be themed
+        let render: String = solved.masked(&puzzle).given(Style::new().fg(Colour::Red.into())).colour(Style::new().fg(Colour::Green.into())).force_styling(true).to_string();
+        assert!(render.contains(&format!("\x1b[31m{}", puzzle.rows.iter().flatten().flatten().next().unwrap())));
+        assert!(render.contains("\x1b[32m"));
+        assert!(!render.contains("\x1b[34m"));
+    }
+
+    #[test]
+    fn test_sudoku_solution_view() {
+        let puzzle: Sudoku = load_sudoku("./tests/example.sdk").unwrap_or_else(|err| panic!("Failed to load Sudoku: {}", err.pretty())).swap_remove(0);
+        let solved: Sudoku = BruteForceSolver::new().run(puzzle);
+
+        // Exactly the non-given cells are green
+        let render: String = solved.solution_view(&puzzle).force_styling(true).to_string();
+        println!("{render}");
+        assert_eq!(render.matches("\x1b[32m").count(), puzzle.count_empty());
+        assert!(render.contains(&format!("\x1b[0m {} \x1b[", puzzle.rows[0][0].unwrap())));
+
+        // Cells that are not solved yet are dim
+        let mut partial: Sudoku = solved;
+        partial.rows[0][1] = None;
+        let render: String = partial.solution_view(&puzzle).force_styling(true).to_string();
+        assert_eq!(render.matches("\x1b[32m").count(), puzzle.count_empty() - 1);
+        assert_eq!(render.matches("\x1b[2m").count(), 1);
+    }
+
+    #[test]
+    fn test_sudoku_legend() {
+        let puzzle: Sudoku = load_sudoku("./tests/example.sdk").unwrap_or_else(|err| panic!("Failed to load Sudoku: {}", err.pretty())).swap_remove(0);
+        let solved: Sudoku = BruteForceSolver::new().run(puzzle);
+
+        // The masked view only explains its colours when asked, under the grid
+        assert!(!solved.masked(&puzzle).to_string().contains("Legend"));
+        let render: String = solved.masked(&puzzle).with_legend(true).force_styling(true).to_string();
+        println!("{render}");
+        let legend: &str = render.lines().last().unwrap();
+        assert!(legend.starts_with("Legend: "));
+        assert!(legend.contains("\x1b[34m\x1b[1mgiven"));
+        assert!(legend.contains("solved") && legend.contains("empty"));
+        assert!(!solved.masked(&puzzle).with_legend(true).with_legend(false).to_string().contains("Legend"));
+
+        // So does the solution view, without changing the grid itself
+        assert!(!solved.solution_view(&puzzle).to_string().contains("Legend"));
+        let render: String = solved.solution_view(&puzzle).with_legend(true).force_styling(true).to_string();
+        println!("{render}");
+        let legend: &str = render.lines().last().unwrap();
+        assert!(legend.starts_with("Legend: given, \x1b[32msolved"));
+        assert!(render.starts_with(&solved.solution_view(&puzzle).force_styling(true).to_string()));
+    }
+
+    #[test]
+    fn test_sudoku_session() {
+        let puzzle: Sudoku = load_sudoku("./tests/example.sdk").unwrap_or_else(|err| panic!("Failed to load Sudoku: {}", err.pretty())).swap_remove(0);
+        let mut session: SudokuSession = SudokuSession::new(puzzle);
+        assert_eq!(session.sudoku(), &puzzle);
+        assert!(session.is_well_formed());
+
+        // Make many random edits, and check the incremental conflict detection agrees with the naive one
+        let mut rng: StdRng = StdRng::seed_from_u64(42);
+        for _ in 0..2000 {
+            let (x, y): (usize, usize) = (rng.gen_range(0..9), rng.gen_range(0..9));
+            let value: Option<u8> = if rng.gen_bool(0.3) { None } else { Some(rng.gen_range(1..=9)) };
+            session.set(x, y, value).unwrap();
+            assert_eq!(session.is_well_formed(), session.sudoku().is_well_formed());
+            let v: u8 = rng.gen_range(1..=9);
+            assert_eq!(session.is_cell_valid(x, y, v), session.sudoku().candidates(x, y).contains(&v));
+        }
+
+        // Clearing everything leaves no conflicts
+        for i in 0..81 { session.clear(i % 9, i / 9).unwrap(); }
+        assert!(session.is_well_formed());
+        assert_eq!(session.conflicts(), 0);
+        assert_eq!(session.into_sudoku(), Sudoku::empty());
+
+        // Conflicts are counted per surplus digit, and givens are respected
+        let mut locked: Sudoku = puzzle;
+        locked.lock_givens();
+        let mut session: SudokuSession = SudokuSession::new(locked);
+        assert_eq!(session.set(0, 0, Some(4)), Err(InvalidReason::LockedCell { cell: (0, 0) }));
+        session.set(1, 0, Some(2)).unwrap();
+        // (It's in conflict with its row, column and box)
+        assert_eq!(session.conflicts(), 3);
+        assert!(!session.is_cell_valid(1, 0, 2));
+        assert_eq!(session.is_cell_valid(0, 0, 2), session.sudoku().candidates(0, 0).contains(&2));
+        session.clear(1, 0).unwrap();
+        assert!(session.is_well_formed());
+        assert!(session.is_cell_valid(0, 0, 2));
+
+        // Values outside of the 1-9 range are never valid, but don't upset the bookkeeping either
+        for value in [ 0, 10, 16, 20, 255 ] {
+            session.set(1, 0, Some(value)).unwrap();
+            assert!(!session.is_well_formed());
+            assert_eq!(session.conflicts(), 0);
+            assert!(!session.is_cell_valid(1, 0, value));
+            session.clear(1, 0).unwrap();
+            assert!(session.is_well_formed());
+        }
+    }
+
+    #[test]
+    fn test_sudoku_equivalent() {
+        let sudoku: Sudoku = load_sudoku("./tests/example.sdk").unwrap_or_else(|err| panic!("Failed to load Sudoku: {}", err.pretty())).swap_remove(0);
+        assert!(sudoku.equivalent(&sudoku));
+
+        // Relabelling gives an equivalent Sudoku
+        let mut other: Sudoku = sudoku;
+        other.swap_digits(1, 9);
+        other.swap_digits(2, 5);
+        assert_ne!(other, sudoku);
+        assert!(sudoku.equivalent(&other));
+
+        // As does transposing and swapping bands, stacks and rows
+        let other: Sudoku = Sudoku::from_fn(|x, y| other.rows[x][y]);
+        let other: Sudoku = Sudoku::from_fn(|x, y| other.rows[[ 6, 7, 8, 3, 4, 5, 1, 0, 2 ][y]][[ 0, 2, 1, 6, 7, 8, 3, 4, 5 ][x]]);
+        assert!(sudoku.equivalent(&other));
+        assert!(other.equivalent(&sudoku));
+
+        // But not mixing rows across bands, or different puzzles
+        let mixed: Sudoku = Sudoku::from_fn(|x, y| sudoku.rows[[ 3, 1, 2, 0, 4, 5, 6, 7, 8 ][y]][x]);
+        assert!(!sudoku.equivalent(&mixed));
+        let different: Sudoku = load_sudoku("./tests/example.sdm").unwrap_or_else(|err| panic!("Failed to load Sudokus: {}", err.pretty())).swap_remove(0);
+        assert!(!sudoku.equivalent(&different));
+    }
+
+    #[test]
+    fn test_sudoku_equals_ignoring_empties() {
+        let puzzle: Sudoku = load_sudoku("./tests/example.sdk").unwrap_or_else(|err| panic!("Failed to load Sudoku: {}", err.pretty())).swap_remove(0);
+        let solution: Sudoku = BruteForceSolver::new().run(puzzle);
+        assert!(puzzle.equals_ignoring_empties(&solution));
+        assert!(solution.equals_ignoring_empties(&puzzle));
+        assert!(Sudoku::empty().equals_ignoring_empties(&solution));
+
+        // A partial result that differs only in cells empty on one side still agrees
+        let mut partial: Sudoku = puzzle;
+        partial.rows[0][0] = None;
+        partial.rows[4][4] = solution.rows[4][4];
+        assert!(partial.equals_ignoring_empties(&puzzle));
+        assert!(partial.equals_ignoring_empties(&solution));
+
+        // But not if some filled-in cell differs
+        partial.rows[4][4] = Some(solution.rows[4][4].unwrap() % 9 + 1);
+        assert!(partial.equals_ignoring_empties(&puzzle));
+        assert!(!partial.equals_ignoring_empties(&solution));
+    }
+
+    #[test]
+    fn test_sudoku_distance() {
+        let puzzle: Sudoku = load_sudoku("./tests/example.sdk").unwrap_or_else(|err| panic!("Failed to load Sudoku: {}", err.pretty())).swap_remove(0);
+        let solution: Sudoku = BruteForceSolver::new().run(puzzle);
+        assert_eq!(puzzle.distance(&puzzle), 0);
+        assert_eq!(puzzle.distance(&solution), puzzle.count_empty());
+        assert_eq!(Sudoku::empty().distance(&solution), 81);
+
+        // Both changed values and cleared cells count
+        let mut other: Sudoku = puzzle;
+        other.rows[0][0] = Some(puzzle.rows[0][0].unwrap() % 9 + 1);
+        assert_eq!(puzzle.distance(&other), 1);
+        assert_eq!(other.distance(&puzzle), 1);
+        other.rows[0][3] = None;
+        assert_eq!(puzzle.distance(&other), 2);
+    }
+
+    #[test]
+    fn test_sudoku_symmetry_type() {
+        // The SudoCue example is rotationally symmetric (and, in fact, has every symmetry)
+        let puzzle: Sudoku = load_sudoku("./tests/example.sdk").unwrap_or_else(|err| panic!("Failed to load Sudoku: {}", err.pretty())).swap_remove(0);
+        let symmetries: Vec<Symmetry> = puzzle.symmetry_type();
+        assert!(symmetries.contains(&Symmetry::Rotational180));
+        assert_eq!(symmetries, Symmetry::ALL.to_vec());
+
+        // A pair of clues opposite of each other is only rotationally symmetric
+        let mut sudoku: Sudoku = Sudoku::empty();
+        sudoku.rows[1][0] = Some(1);
+        sudoku.rows[7][8] = Some(2);
+        assert_eq!(sudoku.symmetry_type(), vec![ Symmetry::Rotational180 ]);
+
+        // Empty Sudokus have all of them, while a single off-centre clue has none
+        assert_eq!(Sudoku::empty().symmetry_type(), Symmetry::ALL.to_vec());
+        let mut sudoku: Sudoku = Sudoku::empty();
+        sudoku.rows[0][1] = Some(1);
+        assert_eq!(sudoku.symmetry_type(), vec![]);
+
+        // Mirroring that clue in the main diagonal makes it diagonally symmetric only
+        sudoku.rows[1][0] = Some(2);
+        assert_eq!(sudoku.symmetry_type(), vec![ Symmetry::Diagonal ]);
+    }
+
+    #[test]
+    fn test_sudoku_side_by_side() {
+        let puzzle: Sudoku = load_sudoku("./tests/example.sdk").unwrap_or_else(|err| panic!("Failed to load Sudoku: {}", err.pretty())).swap_remove(0);
+        let solution: Sudoku = BruteForceSolver::new().run(puzzle);
+        let render: String = puzzle.side_by_side(&solution).to_string();
+        println!("{render}");
+
+        // Every line is a line of the puzzle followed by the same line of the solution
+        let (left, right): (String, String) = (puzzle.to_string(), solution.to_string());
+        assert_eq!(render.lines().count(), 19);
+        for ((line, l), r) in render.lines().zip(left.lines()).zip(right.lines()) {
+            assert_eq!(line, format!("{l}   {r}"));
+        }
+        assert!(render.lines().nth(1).unwrap().starts_with("│ 2 │   │   ║ 1 │   │ 5 ║   │   │ 3 │   │ 2 │"));
+    }
+
+    #[test]
+    fn test_sudoku_pencil_marks() {
+        // Give the top-left cell the five candidates 5-9
+        let mut sudoku: Sudoku = Sudoku::empty();
+        for v in 1..=4 { sudoku.rows[0][v as usize] = Some(v); }
+        assert_eq!(sudoku.candidates(0, 0).len(), 5);
+        let cell = |render: &str| -> Vec<String> { render.lines().skip(1).take(3).map(|l| l.chars().skip(1).take(3).collect()).collect() };
+
+        // By default, all of them are shown, and filled-in cells only show their value
+        let render: String = sudoku.pencil_marks().to_string();
+        println!("\n{render}");
+        assert_eq!(render.lines().count(), 9 * 3 + 10);
+        assert_eq!(cell(&render), vec![ "   ", " 56", "789" ]);
+        assert!(render.lines().nth(2).unwrap().starts_with("│ 56│ 1 │ 2 ║ 3 │ 4 │"));
+
+        // But with a limit of three, the cell is blank
+        let render: String = sudoku.pencil_marks().max_candidates(3).to_string();
+        assert_eq!(cell(&render), vec![ "   ", "   ", "   " ]);
+        assert_eq!(cell(&sudoku.pencil_marks().max_candidates(5).to_string()), vec![ "   ", " 56", "789" ]);
+    }
+
+    #[test]
+    fn test_sudoku_compact_ordered() {
+        let correct: Sudoku = load_sudoku("./tests/correct.json").unwrap_or_else(|err| panic!("Failed to load correct Sudoku: {}", err.pretty())).swap_remove(0);
+        let mut sudoku: Sudoku = load_sudoku("./tests/example.sdk").unwrap_or_else(|err| panic!("Failed to load example Sudoku: {}", err.pretty())).swap_remove(0);
+        sudoku.rows[0][1] = None;
+
+        // Check the orders themselves on the correct board
+        assert_eq!(correct.to_compact_ordered(CellOrder::RowMajor), correct.to_compact_string());
+        assert_eq!(&correct.to_compact_ordered(CellOrder::ColMajor)[..9], "461839527");
+        assert_eq!(&correct.to_compact_ordered(CellOrder::BoxMajor)[..18], "435682197269571834");
+
+        // Every order round-trips
+        for order in [ CellOrder::RowMajor, CellOrder::ColMajor, CellOrder::BoxMajor ] {
+            for board in [ correct, sudoku ] {
+                assert_eq!(Sudoku::from_compact_ordered(&board.to_compact_ordered(order), order), Ok(board));
+            }
+        }
+        assert_ne!(Sudoku::from_compact_ordered(&correct.to_compact_ordered(CellOrder::ColMajor), CellOrder::RowMajor), Ok(correct));
+    }
+
+    #[test]
+    fn test_sudoku_swap_digits() {
+        let correct: Sudoku = load_sudoku("./tests/correct.json").unwrap_or_else(|err| panic!("Failed to load correct Sudoku: {}", err.pretty())).swap_remove(0);
+
+        // Swap once; it's still a solution, but a different one
+        let mut sudoku: Sudoku = correct;
+        sudoku.swap_digits(1, 9);
+        assert_ne!(sudoku, correct);
+        assert!(sudoku.is_finished());
+        assert_eq!(sudoku.to_compact_string(), correct.to_compact_string().chars().map(|c| match c { '1' => '9', '9' => '1', c => c }).collect::<String>());
+
+        // Swapping again restores the original
+        sudoku.swap_digits(1, 9);
+        assert_eq!(sudoku, correct);
+    }
+
+    #[test]
+    fn test_sudoku_normalize() {
+        let sudoku: Sudoku = load_sudoku("./tests/example.sdk").unwrap_or_else(|err| panic!("Failed to load Sudoku: {}", err.pretty())).swap_remove(0);
+
+        // Zeroes are not valid values, but normalize to empty cells
+        let mut zeroed: Sudoku = sudoku;
+        zeroed.rows[0][1] = Some(0);
+        assert_eq!(zeroed.well_formed(), Err(InvalidReason::InvalidValue { cell: (1, 0), value: 0 }));
+        assert_eq!(zeroed.normalize(), Ok(()));
+        assert_eq!(zeroed.rows[0][1], None);
+        assert_eq!(zeroed, sudoku);
+
+        // Too large values are rejected
+        let mut large: Sudoku = zeroed;
+        large.rows[0][1] = Some(0);
+        large.rows[8][7] = Some(10);
+        assert_eq!(large.well_formed(), Err(InvalidReason::InvalidValue { cell: (1, 0), value: 0 }));
+        assert_eq!(large.normalize(), Err(InvalidReason::InvalidValue { cell: (7, 8), value: 10 }));
+        assert_eq!(large.rows[0][1], Some(0));
+    }
+
+    #[test]
+    fn test_sudoku_fill_random_valid() {
+        for seed in 0..100 {
+            let sudoku: Sudoku = Sudoku::fill_random_valid(&mut StdRng::seed_from_u64(seed));
+            assert_eq!(sudoku.well_formed(), Ok(()), "Generated Sudoku is not well-formed:\n{sudoku}");
+            assert!(!sudoku.is_empty() && !sudoku.is_full());
+        }
+    }
+
+    #[test]
+    fn test_forced_cells() {
+        let correct: Sudoku = load_sudoku("./tests/correct.json").unwrap_or_else(|err| panic!("Failed to load correct Sudoku: {}", err.pretty())).swap_remove(0);
+
+        // Find a second solution by swapping a deadly rectangle
+        let mut other: Sudoku = correct;
+        other.rows[0][2] = correct.rows[0][3];
+        other.rows[0][3] = correct.rows[0][2];
+        other.rows[1][2] = correct.rows[1][3];
+        other.rows[1][3] = correct.rows[1][2];
+
+        // Only the rectangle is uncertain
+        let forced: Sudoku = forced_cells(&[ correct, other ]);
+        for y in 0..9 {
+            for x in 0..9 {
+                if y < 2 && (x == 2 || x == 3) {
+                    assert_eq!(forced.rows[y][x], None);
+                } else {
+                    assert_eq!(forced.rows[y][x], correct.rows[y][x]);
+                }
+            }
+        }
+
+        // Edge cases
+        assert_eq!(forced_cells(&[ correct ]), correct);
+        assert!(forced_cells(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_sudoku_candidates_out_of_range() {
+        // Out-of-range peers are ignored instead of panicking
+        let mut sudoku: Sudoku = Sudoku::empty();
+        for v in 1..=7 { sudoku.rows[0][v as usize] = Some(v); }
+        sudoku.rows[0][8] = Some(20);
+        assert_eq!(sudoku.candidates(0, 0), vec![ 8, 9 ]);
+        assert_eq!(sudoku.cell_valid(8, 0, 20), Err(InvalidReason::InvalidValue { cell: (8, 0), value: 20 }));
+
+        // So everything built on top of them doesn't panic either
+        let grid: CandidateGrid = sudoku.to_candidates();
+        assert_eq!(grid.candidates(0, 0), vec![ 8, 9 ]);
+        assert!(grid.candidates(8, 0).is_empty());
+        assert_eq!(sudoku.trivial_fill_count(), 0);
+        let mut placed: Sudoku = sudoku;
+        assert!(!placed.place_if_valid(0, 0, 1));
+        assert!(placed.place_if_valid(0, 0, 9));
+        sudoku.rows[1][0] = Some(9);
+        assert_eq!(sudoku.fill_forced(), 1);
+        assert_eq!(sudoku.rows[0][0], Some(8));
+    }
+
+    #[test]
+    fn test_sudoku_trivial_fill_count() {
+        let easy: Sudoku = load_sudoku("./tests/example.sdk").unwrap_or_else(|err| panic!("Failed to load Sudoku: {}", err.pretty())).swap_remove(0);
+        let hard: Sudoku = load_sudoku("./tests/example.sdm").unwrap_or_else(|err| panic!("Failed to load Sudokus: {}", err.pretty())).swap_remove(0);
+
+        // The easy one falls entirely to naked singles, the hard one not at all
+        assert_eq!(easy.trivial_fill_count(), easy.count_empty());
+        assert_eq!(hard.trivial_fill_count(), 0);
+        assert_eq!(BruteForceSolver::new().run(easy).trivial_fill_count(), 0);
+
+        // The Sudoku itself is left untouched
+        let original: Sudoku = easy;
+        easy.trivial_fill_count();
+        assert_eq!(easy, original);
+    }
+
+    #[test]
+    fn test_sudoku_try_with_values() {
+        let sudoku: Sudoku = load_sudoku("./tests/example.sdk").unwrap_or_else(|err| panic!("Failed to load Sudoku: {}", err.pretty())).swap_remove(0);
+        assert_eq!(Sudoku::try_with_values(sudoku.rows), Ok(sudoku));
+
+        // Out-of-range values are rejected
+        let mut rows: [ [ Option<u8>; 9 ]; 9 ] = sudoku.rows;
+        rows[4][2] = Some(10);
+        assert_eq!(Sudoku::try_with_values(rows), Err(InvalidReason::InvalidValue { cell: (2, 4), value: 10 }));
+        rows[1][0] = Some(0);
+        assert_eq!(Sudoku::try_with_values(rows), Err(InvalidReason::InvalidValue { cell: (0, 1), value: 0 }));
+    }
+
+    #[test]
+    fn test_sudoku_try_from_vec() {
+        let sudoku: Sudoku = load_sudoku("./tests/example.sdk").unwrap_or_else(|err| panic!("Failed to load Sudoku: {}", err.pretty())).swap_remove(0);
+        let rows: Vec<Vec<Option<u8>>> = sudoku.rows.iter().map(|r| r.to_vec()).collect();
+        assert_eq!(Sudoku::try_from(rows.clone()), Ok(sudoku));
+
+        // Ragged or wrongly sized vectors are rejected
+        let mut ragged: Vec<Vec<Option<u8>>> = rows.clone();
+        ragged[3].pop();
+        assert_eq!(Sudoku::try_from(ragged), Err(VecConversionError::IncorrectRowLength { row: 3, got: 8 }));
+        assert_eq!(Sudoku::try_from(rows[..8].to_vec()), Err(VecConversionError::IncorrectRowCount { got: 8 }));
+        assert_eq!(Sudoku::try_from(Vec::new()), Err(VecConversionError::IncorrectRowCount { got: 0 }));
+
+        // As are out-of-range values
+        let mut invalid: Vec<Vec<Option<u8>>> = rows;
+        invalid[1][7] = Some(10);
+        assert_eq!(Sudoku::try_from(invalid), Err(VecConversionError::InvalidValue { err: InvalidReason::InvalidValue { cell: (7, 1), value: 10 } }));
+    }
+
+    #[test]
+    fn test_sudoku_bytes() {
+        let sudoku: Sudoku = load_sudoku("./tests/example.sdk").unwrap_or_else(|err| panic!("Failed to load Sudoku: {}", err.pretty())).swap_remove(0);
+
+        // Round-trip it
+        let bytes: [ u8; 81 ] = sudoku.to_bytes();
+        assert_eq!(&bytes[..9], &[ 2, 0, 0, 1, 0, 5, 0, 0, 3 ]);
+        assert_eq!(Sudoku::from_bytes(&bytes), Ok(sudoku));
+
+        // Out-of-range values are rejected
+        let mut bytes: [ u8; 81 ] = bytes;
+        bytes[10] = 10;
+        assert_eq!(Sudoku::from_bytes(&bytes), Err(InvalidReason::InvalidValue { cell: (1, 1), value: 10 }));
+    }
+
+    #[test]
+    fn test_sudoku_from_fn() {
+        // Build a diagonal
+        let sudoku: Sudoku = Sudoku::from_fn(|x, y| if x == y { Some(1) } else { None });
+        println!("\n{sudoku}");
+        let mut expected: Sudoku = Sudoku::empty();
+        for (i, row) in expected.rows.iter_mut().enumerate() { row[i] = Some(1); }
+        assert_eq!(sudoku, expected);
+
+        // Cells are visited in row-major order
+        let mut i: u8 = 0;
+        let sudoku: Sudoku = Sudoku::from_fn(|_, _| { i += 1; Some(i % 9 + 1) });
+        assert_eq!(sudoku.rows[0][..3], [ Some(2), Some(3), Some(4) ]);
+        assert_eq!(sudoku.rows[1][0], Some(2));
+    }
+
+    #[test]
+    fn test_sudoku_iters() {
+        let correct: Sudoku = load_sudoku("./tests/correct.json").unwrap_or_else(|err| panic!("Failed to load correct Sudoku: {}", err.pretty())).swap_remove(0);
+
+        // Check the rows
+        assert_eq!(correct.rows_iter().count(), 9);
+        assert_eq!(correct.rows_iter().next(), Some([ Some(4), Some(3), Some(5), Some(2), Some(6), Some(9), Some(7), Some(8), Some(1) ]));
+        assert!(correct.rows_iter().eq(correct.rows.into_iter()));
+
+        // Check the columns
+        assert_eq!(correct.cols_iter().count(), 9);
+        assert_eq!(correct.cols_iter().next(), Some([ Some(4), Some(6), Some(1), Some(8), Some(3), Some(9), Some(5), Some(2), Some(7) ]));
+        assert_eq!(correct.cols_iter().last(), Some([ Some(1), Some(3), Some(2), Some(7), Some(5), Some(8), Some(4), Some(6), Some(9) ]));
+    }
 }
 
 
@@ -75,10 +848,15 @@ mod tests {
 
 /***** ERRORS *****/
 /// Explains why a cell isn't valid.
-#[derive(Clone, Copy, Debug, EnumDebug, Eq, Hash, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "std", derive(EnumDebug))]
 pub enum InvalidReason {
     /// A cell was empty (only for [`Sudoku::finished()`])
     EmptyCell{ cell: (usize, usize) },
+    /// A cell is a locked given, and cannot be changed.
+    LockedCell{ cell: (usize, usize) },
+    /// A cell has a value that is not in the range 1-9.
+    InvalidValue{ cell: (usize, usize), value: u8 },
 
     /// There is a conflicting cell in the cell's row.
     RowConflict{ cell: (usize, usize), conflict: (usize, usize) },
@@ -91,7 +869,9 @@ impl Display for InvalidReason {
     fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
         use InvalidReason::*;
         match self {
-            EmptyCell { cell: (x, y) } => write!(f, "Cell ({},{}) is empty", x + 1, y + 2),
+            EmptyCell { cell: (x, y) }           => write!(f, "Cell ({},{}) is empty", x + 1, y + 2),
+            LockedCell { cell: (x, y) }          => write!(f, "Cell ({},{}) is a locked given", x + 1, y + 1),
+            InvalidValue { cell: (x, y), value } => write!(f, "Cell ({},{}) has invalid value {} (must be 1-9)", x + 1, y + 1, value),
 
             RowConflict { cell: (x1, y1), conflict: (x2, y2) } => write!(f, "Cell ({},{}) conflicts with cell ({},{}) in the same row", x1 + 1, y1 + 1, x2 + 1, y2 + 1),
             ColConflict { cell: (x1, y1), conflict: (x2, y2) } => write!(f, "Cell ({},{}) conflicts with cell ({},{}) in the same column", x1 + 1, y1 + 1, x2 + 1, y2 + 1),
@@ -101,22 +881,369 @@ impl Display for InvalidReason {
 }
 impl Error for InvalidReason {}
 
+/// Describes what can go wrong when parsing [`Sudoku`]s from compact strings.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum CompactParseError {
+    /// The string did not have exactly 81 cells.
+    IncorrectLength { got: usize },
+    /// Got an illegal character for a cell.
+    IllegalCellChar { cell: usize, got: char },
+}
+impl Display for CompactParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        use CompactParseError::*;
+        match self {
+            IncorrectLength { got }      => write!(f, "Compact Sudoku has {got} cells, expected 81"),
+            IllegalCellChar { cell, got } => write!(f, "Encountered illegal cell character '{got}' in cell {cell}"),
+        }
+    }
+}
+impl Error for CompactParseError {}
+
+/// Describes what can go wrong when converting nested [`Vec`]s to [`Sudoku`]s.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum VecConversionError {
+    /// There were not exactly 9 rows.
+    IncorrectRowCount { got: usize },
+    /// A row did not have exactly 9 cells.
+    IncorrectRowLength { row: usize, got: usize },
+    /// A cell had a value outside of 1-9.
+    InvalidValue { err: InvalidReason },
+}
+impl Display for VecConversionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        use VecConversionError::*;
+        match self {
+            IncorrectRowCount { got }       => write!(f, "Sudoku has {got} rows, expected 9"),
+            IncorrectRowLength { row, got } => write!(f, "Row {} has {} cells, expected 9", row + 1, got),
+            InvalidValue { .. }             => write!(f, "Sudoku has an invalid value"),
+        }
+    }
+}
+impl Error for VecConversionError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        use VecConversionError::*;
+        match self {
+            IncorrectRowCount { .. }  => None,
+            IncorrectRowLength { .. } => None,
+            InvalidValue { err }      => Some(err),
+        }
+    }
+}
+
+
+
+
+
+/***** HELPER FUNCTIONS *****/
+/// All permutations of three elements.
+const PERMUTATIONS_3: [ [ usize; 3 ]; 6 ] = [ [ 0, 1, 2 ], [ 0, 2, 1 ], [ 1, 0, 2 ], [ 1, 2, 0 ], [ 2, 0, 1 ], [ 2, 1, 0 ] ];
+
+/// Generates all permutations of the rows of a Sudoku that preserve its bands (i.e., permuting the bands, and the rows within every band).
+/// 
+/// Equivalently, these are all stack-preserving permutations of the columns.
+/// 
+/// # Returns
+/// A list of all 1296 permutations, where the `i`th element of each is the index of the row that goes to position `i`.
+fn band_permutations() -> Vec<[ usize; 9 ]> {
+    let mut perms: Vec<[ usize; 9 ]> = Vec::with_capacity(1296);
+    for bands in PERMUTATIONS_3 {
+        for p0 in PERMUTATIONS_3 {
+            for p1 in PERMUTATIONS_3 {
+                for p2 in PERMUTATIONS_3 {
+                    let within: [ [ usize; 3 ]; 3 ] = [ p0, p1, p2 ];
+                    perms.push(core::array::from_fn(|i| 3 * bands[i / 3] + within[i / 3][i % 3]));
+                }
+            }
+        }
+    }
+    perms
+}
+
+/// Attempts to find a stack-preserving permutation of the columns of one grid, together with a relabelling of the digits, that turns it into another.
+/// 
+/// # Arguments
+/// - `a`: The grid whose columns to permute.
+/// - `b`: The grid to match.
+/// - `c`: The column in `b` to match next. Columns before it have already been matched.
+/// - `chosen`: The columns in `a` that have been matched to the columns in `b` so far.
+/// - `fwd`: The relabelling from digits in `a` to digits in `b` so far (`0` means unassigned).
+/// - `bwd`: The inverse of `fwd`.
+/// 
+/// # Returns
+/// True if the remaining columns can be matched, or false otherwise. Cells with values outside of 1-9 never match.
+fn match_columns(a: &[ [ Option<u8>; 9 ]; 9 ], b: &[ [ Option<u8>; 9 ]; 9 ], c: usize, chosen: &mut [ usize; 9 ], fwd: [ u8; 10 ], bwd: [ u8; 10 ]) -> bool {
+    if c == 9 { return true; }
+    for src in 0..9 {
+        // Only consider unused columns in the same stack as the rest of this stack
+        if chosen[..c].contains(&src) || (!c.is_multiple_of(3) && src / 3 != chosen[c - c % 3] / 3) { continue; }
+
+        // See if the column matches under the relabelling (extending it where needed)
+        let (mut fwd, mut bwd): ([ u8; 10 ], [ u8; 10 ]) = (fwd, bwd);
+        let matches: bool = (0..9).all(|y| match (a[y][src], b[y][c]) {
+            (None, None) => true,
+            (Some(va), Some(vb)) if (1..=9).contains(&va) && (1..=9).contains(&vb) => {
+                if fwd[va as usize] == 0 && bwd[vb as usize] == 0 {
+                    fwd[va as usize] = vb;
+                    bwd[vb as usize] = va;
+                }
+                fwd[va as usize] == vb
+            },
+            _ => false,
+        });
+        if matches {
+            chosen[c] = src;
+            if match_columns(a, b, c + 1, chosen, fwd, bwd) { return true; }
+        }
+    }
+    false
+}
+
 
 
 
 
 /***** FORMATTERS *****/
+/// Formats the Sudoku as a single line of 81 cells.
+#[derive(Debug)]
+pub struct SudokuCompactFormatter<'s> {
+    /// The Sudoku to format.
+    sudoku : &'s Sudoku,
+}
+impl<'s> Display for SudokuCompactFormatter<'s> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        for cell in self.sudoku.rows.iter().flatten() {
+            match cell {
+                Some(value) => write!(f, "{value}")?,
+                None        => write!(f, ".")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Formats two Sudokus next to each other, e.g., a puzzle and its solution.
+#[derive(Debug)]
+pub struct SudokuSideBySideFormatter<'s, 'o> {
+    /// The Sudoku to format on the left.
+    left  : &'s Sudoku,
+    /// The Sudoku to format on the right.
+    right : &'o Sudoku,
+}
+impl<'s, 'o> Display for SudokuSideBySideFormatter<'s, 'o> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        // Render both separately, and then interleave their lines
+        let left  : String = format!("{}", self.left);
+        let right : String = format!("{}", self.right);
+        for (l, r) in left.lines().zip(right.lines()) {
+            writeln!(f, "{l}   {r}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Formats the Sudoku with the candidates (i.e., pencil marks) of every empty cell.
+/// 
+/// Every cell takes three lines, where empty cells show their candidates in a 3x3 grid and filled-in cells show their value in the middle.
+#[derive(Debug)]
+pub struct SudokuCandidateFormatter<'s> {
+    /// The Sudoku to format.
+    sudoku : &'s Sudoku,
+    /// If given, cells with more candidates than this are shown blank.
+    max    : Option<usize>,
+}
+impl<'s> SudokuCandidateFormatter<'s> {
+    /// Limits which cells show their candidates, to keep the board readable when most cells still have many of them.
+    /// 
+    /// # Arguments
+    /// - `max`: The maximum number of candidates a cell may have to show them. Cells with more are shown blank.
+    /// 
+    /// # Returns
+    /// A mutable reference to self for chaining.
+    #[inline]
+    pub fn max_candidates(&mut self, max: usize) -> &mut Self {
+        self.max = Some(max);
+        self
+    }
+}
+impl<'s> Display for SudokuCandidateFormatter<'s> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        let candidates: CandidateGrid = self.sudoku.to_candidates();
+
+        // Generate the rows...
+        for y in 0..9 {
+            // Generate the top thing if needed
+            if y == 0 {
+                writeln!(f, "┌───┬───┬───╥───┬───┬───╥───┬───┬───┐")?;
+            }
+
+            // Print the values in this row, one line of candidates at a time
+            for line in 0..3 {
+                write!(f, "│")?;
+                for x in 0..9 {
+                    match self.sudoku.rows[y][x] {
+                        Some(value) if line == 1 => write!(f, " {value} ")?,
+                        Some(_)                  => write!(f, "   ")?,
+                        None => {
+                            let shown: bool = self.max.map(|max| candidates.rows[y][x].count_ones() as usize <= max).unwrap_or(true);
+                            for v in (3 * line + 1)..=(3 * line + 3) {
+                                if shown && candidates.contains(x, y, v as u8) { write!(f, "{v}")?; } else { write!(f, " ")?; }
+                            }
+                        },
+                    }
+                    if x < 8 && x % 3 == 2 { write!(f, "║")?; }
+                    else { write!(f, "│")?; }
+                }
+                writeln!(f)?;
+            }
+
+            // Print the bottom thing
+            if y < 8 && y % 3 == 2 {
+                writeln!(f, "╞═══╪═══╪═══╬═══╪═══╪═══╬═══╪═══╪═══╡")?;
+            } else if y < 8 {
+                writeln!(f, "├───┼───┼───╫───┼───┼───╫───┼───┼───┤")?;
+            } else {
+                writeln!(f, "└───┴───┴───╨───┴───┴───╨───┴───┴───┘")?;
+            }
+        }
+
+        // Done
+        Ok(())
+    }
+}
+
 /// Formats the Sudoku with colour.
+#[cfg(feature = "std")]
 #[derive(Debug)]
 pub struct SudokuColourFormatter<'s> {
     /// The Sudoku to format.
     sudoku : &'s Sudoku,
 }
-impl<'s> Display for SudokuColourFormatter<'s> {
+#[cfg(feature = "std")]
+impl<'s> Display for SudokuColourFormatter<'s> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        // Define the colours
+        let cell  : Style = Style::new().bold();
+        let given : Style = Style::new().bold().blue();
+        let gray  : Style = Style::new().black().bright();
+
+        // Generate the rows...
+        for y in 0..9 {
+            // Generate the top thing if needed
+            if y == 0 {
+                writeln!(f, "{}", gray.apply_to("┌───┬───┬───╥───┬───┬───╥───┬───┬───┐"))?;
+            }
+
+            // Print the values in this row
+            write!(f, "{}", gray.apply_to("│"))?;
+            for x in 0..9 {
+                let svalue: String = self.sudoku.rows[y][x].map(|i| format!("{i}")).unwrap_or(" ".into());
+                if self.sudoku.is_given(x, y) {
+                    write!(f, " {} ", given.apply_to(svalue))?;
+                } else {
+                    write!(f, " {} ", cell.apply_to(svalue))?;
+                }
+                if x < 8 && x % 3 == 2 { write!(f, "{}", gray.apply_to("║"))?; }
+                else { write!(f, "{}", gray.apply_to("│"))?; }
+            }
+            writeln!(f)?;
+
+            // Print the bottom thing
+            if y < 8 && y % 3 == 2 {
+                writeln!(f, "{}", gray.apply_to("╞═══╪═══╪═══╬═══╪═══╪═══╬═══╪═══╪═══╡"))?;
+            } else if y < 8 {
+                writeln!(f, "{}", gray.apply_to("├───┼───┼───╫───┼───┼───╫───┼───┼───┤"))?;
+            } else {
+                writeln!(f, "{}", gray.apply_to("└───┴───┴───╨───┴───┴───╨───┴───┴───┘"))?;
+            }
+        }
+
+        // Done
+        Ok(())
+    }
+}
+
+/// Formats the Sudoku with colour and a mask to determine which are 'fixed' numbers.
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct SudokuMaskFormatter<'s, 'm> {
+    /// The Sudoku to format.
+    sudoku : &'s Sudoku,
+    /// The mask to apply.
+    mask   : &'m Sudoku,
+    /// The colour for the givens (i.e., values in the mask).
+    given  : Style,
+    /// The colour for typical things.
+    colour : Style,
+    /// Whether to print a legend under the grid.
+    legend : bool,
+    /// Whether to force styling on or off regardless of the terminal, or `None` to let [`console`] decide.
+    force  : Option<bool>,
+}
+#[cfg(feature = "std")]
+impl<'s, 'm> SudokuMaskFormatter<'s, 'm> {
+    /// Overrides the colour for the givens.
+    /// 
+    /// # Arguments
+    /// - `colour`: The [`Style`] that determines the new colour.
+    /// 
+    /// # Returns
+    /// A mutable reference to self for chaining.
+    #[inline]
+    pub fn given(&mut self, colour: impl Into<Style>) -> &mut Self {
+        self.given = colour.into();
+        self
+    }
+
+    /// Overrides the colour for the found solutions.
+    /// 
+    /// # Arguments
+    /// - `colour`: The [`Style`] that determines the new colour.
+    /// 
+    /// # Returns
+    /// A mutable reference to self for chaining.
+    #[inline]
+    pub fn colour(&mut self, colour: impl Into<Style>) -> &mut Self {
+        self.colour = colour.into();
+        self
+    }
+
+    /// Sets whether to print a legend under the grid that explains the colours.
+    /// 
+    /// # Arguments
+    /// - `legend`: Whether to print the legend or not.
+    /// 
+    /// # Returns
+    /// A mutable reference to self for chaining.
+    #[inline]
+    pub fn with_legend(&mut self, legend: bool) -> &mut Self {
+        self.legend = legend;
+        self
+    }
+
+    /// Forces the colours to be emitted (or not), regardless of whether the terminal supports them.
+    /// 
+    /// # Arguments
+    /// - `force`: Whether to always (true) or never (false) emit colours.
+    /// 
+    /// # Returns
+    /// A mutable reference to self for chaining.
+    #[inline]
+    pub fn force_styling(&mut self, force: bool) -> &mut Self {
+        self.force = Some(force);
+        self
+    }
+}
+#[cfg(feature = "std")]
+impl<'s, 'm> Display for SudokuMaskFormatter<'s, 'm> {
     fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
         // Define the colours
-        let cell: Style = Style::new().bold();
-        let gray: Style = Style::new().black().bright();
+        let style  = |style: Style| -> Style { match self.force { Some(force) => style.force_styling(force), None => style } };
+        let given  : Style = style(self.given.clone());
+        let colour : Style = style(self.colour.clone());
+        let error  : Style = style(Style::new().black().on_red());
+        let gray   : Style = style(Style::new().black().bright());
+        let empty  : Style = style(Style::new().dim());
 
         // Generate the rows...
         for y in 0..9 {
@@ -128,7 +1255,21 @@ impl<'s> Display for SudokuColourFormatter<'s> {
             // Print the values in this row
             write!(f, "{}", gray.apply_to("│"))?;
             for x in 0..9 {
-                write!(f, " {} ", cell.apply_to(self.sudoku.rows[y][x].map(|i| format!("{i}")).unwrap_or(" ".into())))?;
+                let value  : Option<u8> = self.sudoku.rows[y][x];
+                let svalue : String     = value.map(|i| format!("{i}")).unwrap_or(" ".into());
+
+                // WRite it with bold or not, depending on the mask (and show cells that are still empty distinctly)
+                if value.is_none() && self.mask.rows[y][x].is_none() {
+                    write!(f, " {} ", empty.apply_to("·"))?;
+                } else if value == self.mask.rows[y][x] {
+                    write!(f, " {} ", given.apply_to(svalue))?;
+                } else if self.mask.rows[y][x].is_some() {
+                    write!(f, " {} ", error.apply_to(svalue))?;
+                } else {
+                    write!(f, " {} ", colour.apply_to(svalue))?;
+                }
+
+                // Write the border
                 if x < 8 && x % 3 == 2 { write!(f, "{}", gray.apply_to("║"))?; }
                 else { write!(f, "{}", gray.apply_to("│"))?; }
             }
@@ -144,41 +1285,65 @@ impl<'s> Display for SudokuColourFormatter<'s> {
             }
         }
 
+        // Explain the colours if asked
+        if self.legend {
+            writeln!(f, "Legend: {}, {}, {} empty, {}", given.apply_to("given"), colour.apply_to("solved"), empty.apply_to("·"), error.apply_to("conflicts with given"))?;
+        }
+
         // Done
         Ok(())
     }
 }
 
-/// Formats the Sudoku with colour and a mask to determine which are 'fixed' numbers.
+/// Formats a (possibly partial) solution with colour, keyed by the puzzle it solves.
+#[cfg(feature = "std")]
 #[derive(Debug)]
-pub struct SudokuMaskFormatter<'s, 'm> {
-    /// The Sudoku to format.
-    sudoku : &'s Sudoku,
-    /// The mask to apply.
-    mask   : &'m Sudoku,
-    /// The colour for typical things.
-    colour : Style,
+pub struct SudokuSolutionFormatter<'s, 'p> {
+    /// The solution to format.
+    solution : &'s Sudoku,
+    /// The puzzle that determines which cells are givens.
+    puzzle   : &'p Sudoku,
+    /// Whether to print a legend under the grid.
+    legend   : bool,
+    /// Whether to force styling on or off regardless of the terminal, or `None` to let [`console`] decide.
+    force    : Option<bool>,
 }
-impl<'s, 'm> SudokuMaskFormatter<'s, 'm> {
-    /// Overrides the colour for the found solutions.
+#[cfg(feature = "std")]
+impl<'s, 'p> SudokuSolutionFormatter<'s, 'p> {
+    /// Sets whether to print a legend under the grid that explains the colours.
     /// 
     /// # Arguments
-    /// - `colour`: The [`Style`] that determines the new colour.
+    /// - `legend`: Whether to print the legend or not.
     /// 
     /// # Returns
     /// A mutable reference to self for chaining.
     #[inline]
-    pub fn colour(&mut self, colour: impl Into<Style>) -> &mut Self {
-        self.colour = colour.into();
+    pub fn with_legend(&mut self, legend: bool) -> &mut Self {
+        self.legend = legend;
+        self
+    }
+
+    /// Forces the colours to be emitted (or not), regardless of whether the terminal supports them.
+    /// 
+    /// # Arguments
+    /// - `force`: Whether to always (true) or never (false) emit colours.
+    /// 
+    /// # Returns
+    /// A mutable reference to self for chaining.
+    #[inline]
+    pub fn force_styling(&mut self, force: bool) -> &mut Self {
+        self.force = Some(force);
         self
     }
 }
-impl<'s, 'm> Display for SudokuMaskFormatter<'s, 'm> {
+#[cfg(feature = "std")]
+impl<'s, 'p> Display for SudokuSolutionFormatter<'s, 'p> {
     fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
         // Define the colours
-        let masked : Style = Style::new().bold().blue();
-        let error  : Style = Style::new().black().on_red();
-        let gray   : Style = Style::new().black().bright();
+        let style  = |style: Style| -> Style { match self.force { Some(force) => style.force_styling(force), None => style } };
+        let solved : Style = style(Style::new().green());
+        let gray   : Style = style(Style::new().black().bright());
+        let empty  : Style = style(Style::new().dim());
 
         // Generate the rows...
         for y in 0..9 {
@@ -187,19 +1352,13 @@ impl<'s, 'm> Display for SudokuMaskFormatter<'s, 'm> {
                 writeln!(f, "{}", gray.apply_to("┌───┬───┬───╥───┬───┬───╥───┬───┬───┐"))?;
             }
 
-            // Print the values in this row
+            // Print the values in this row, taking givens from the puzzle and the rest from the solution
             write!(f, "{}", gray.apply_to("│"))?;
             for x in 0..9 {
-                let value  : Option<u8> = self.sudoku.rows[y][x];
-                let svalue : String     = value.map(|i| format!("{i}")).unwrap_or(" ".into());
-
-                // WRite it with bold or not, depending on the mask
-                if value == self.mask.rows[y][x] {
-                    write!(f, " {} ", masked.apply_to(svalue))?;
-                } else if self.mask.rows[y][x].is_some() {
-                    write!(f, " {} ", error.apply_to(svalue))?;
-                } else {
-                    write!(f, " {} ", self.colour.apply_to(svalue))?;
+                match (self.puzzle.rows[y][x], self.solution.rows[y][x]) {
+                    (Some(given), _)    => write!(f, " {given} ")?,
+                    (None, Some(value)) => write!(f, " {} ", solved.apply_to(value))?,
+                    (None, None)        => write!(f, " {} ", empty.apply_to("·"))?,
                 }
 
                 // Write the border
@@ -218,6 +1377,11 @@ impl<'s, 'm> Display for SudokuMaskFormatter<'s, 'm> {
             }
         }
 
+        // Explain the colours if asked
+        if self.legend {
+            writeln!(f, "Legend: given, {}, {} unsolved", solved.apply_to("solved"), empty.apply_to("·"))?;
+        }
+
         // Done
         Ok(())
     }
@@ -227,18 +1391,83 @@ impl<'s, 'm> Display for SudokuMaskFormatter<'s, 'm> {
 
 
 
+/***** LIBRARY FUNCTIONS *****/
+/// Finds the cells on which a number of Sudokus all agree.
+/// 
+/// This is useful to find out which cells are certain when a puzzle has multiple solutions.
+/// 
+/// # Arguments
+/// - `boards`: The [`Sudoku`]s to intersect.
+/// 
+/// # Returns
+/// A new [`Sudoku`] where a cell is `Some(v)` iff every board in `boards` has `v` in that cell, or [`None`] otherwise. If `boards` is empty, returns an [empty](Sudoku::empty()) Sudoku.
+pub fn forced_cells(boards: &[Sudoku]) -> Sudoku {
+    let Some(first) = boards.first() else { return Sudoku::empty(); };
+    Sudoku::from_fn(|x, y| if boards.iter().all(|b| b.rows[y][x] == first.rows[y][x]) { first.rows[y][x] } else { None })
+}
+
+/// Builds the exact-cover formulation of a Sudoku, e.g., to inspect what a dancing links (DLX) solver would work with.
+/// 
+/// The full matrix has 729 rows, one for every placement of a value `v` in a cell `(x, y)`, and 324 columns, one for every constraint:
+/// - `0..81`: Cell `(x, y)` has a value (at `9 * y + x`);
+/// - `81..162`: Row `y` has value `v` (at `81 + 9 * y + v - 1`);
+/// - `162..243`: Column `x` has value `v` (at `162 + 9 * x + v - 1`); and
+/// - `243..324`: Box `b` has value `v` (at `243 + 9 * b + v - 1`).
+/// 
+/// # Arguments
+/// - `sudoku`: The [`Sudoku`] to build the matrix for.
+/// 
+/// # Returns
+/// The rows of the matrix that remain active for the given board, in row-major order of the cells and then ascending value. Filled cells only keep the row of their value, and empty cells keep the rows of their [candidates](Sudoku::candidates()). Every row has 324 entries, of which exactly 4 are true.
+pub fn dlx_matrix(sudoku: &Sudoku) -> Vec<Vec<bool>> {
+    let mut matrix: Vec<Vec<bool>> = Vec::new();
+    for (x, y) in (0..81).map(|i| (i % 9, i / 9)) {
+        let values: Vec<u8> = match sudoku.rows[y][x] {
+            Some(v) => vec![ v ],
+            None    => sudoku.candidates(x, y),
+        };
+        for v in values.into_iter().filter(|v| (1..=9).contains(v)) {
+            let (v, b): (usize, usize) = (v as usize - 1, 3 * (y / 3) + x / 3);
+            let mut row: Vec<bool> = vec![ false; 324 ];
+            row[9 * y + x] = true;
+            row[81 + 9 * y + v] = true;
+            row[162 + 9 * x + v] = true;
+            row[243 + 9 * b + v] = true;
+            matrix.push(row);
+        }
+    }
+    matrix
+}
+
+
+
+
+
 /***** LIBRARY *****/
 /// Represents a single Sudoku.
-#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+/// 
+/// # Construction and equality
+/// Because a Sudoku may carry a (private) mask of [locked givens](Sudoku::lock_givens()), it cannot be built with a struct literal. Use a constructor like [`Sudoku::empty()`], [`Sudoku::with_values()`] or [`Sudoku::from_fn()`] instead.
+/// 
+/// Equality only compares the [`rows`](Sudoku::rows), **not** which cells are locked. I.e., a board is equal to itself after calling [`Sudoku::lock_givens()`]. Compare [`Sudoku::givens()`] too if the lock state matters.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
 pub struct Sudoku {
     /// It's a 9x9 grid of cells!
     pub rows : [ [ Option<u8>; 9 ]; 9 ],
+    /// If given, marks which cells are locked givens that may not be changed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    givens   : Option<[ [ bool; 9 ]; 9 ]>,
 }
 
 impl Default for Sudoku {
     #[inline]
     fn default() -> Self { Self::empty() }
 }
+impl Eq for Sudoku {}
+impl PartialEq for Sudoku {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool { self.rows == other.rows }
+}
 impl Sudoku {
     /// Constructor for an empty Sudoku.
     /// 
@@ -247,12 +1476,15 @@ impl Sudoku {
     #[inline]
     pub fn empty() -> Self {
         Self {
-            rows : [ [ None; 9 ]; 9 ],
+            rows   : [ [ None; 9 ]; 9 ],
+            givens : None,
         }
     }
 
     /// Constructor for a Sudoku with given values.
     /// 
+    /// Note that this does not check the values. Prefer [`Sudoku::try_with_values()`] for values that come from outside.
+    /// 
     /// # Arguments
     /// - `rows`: The rows to initialize the Sudoku with.
     /// 
@@ -261,8 +1493,31 @@ impl Sudoku {
     #[inline]
     pub fn with_values(rows: impl Into<[ [ Option<u8>; 9 ]; 9 ]>) -> Self {
         Self {
-            rows : rows.into(),
+            rows   : rows.into(),
+            givens : None,
+        }
+    }
+
+    /// Constructor for a Sudoku with given values that checks whether they are in range.
+    /// 
+    /// # Arguments
+    /// - `rows`: The rows to initialize the Sudoku with.
+    /// 
+    /// # Returns
+    /// A new instance of Self with the given values in the cells.
+    /// 
+    /// # Errors
+    /// This function errors with an [`InvalidReason::InvalidValue`] for the first cell (in row-major order) that has a value outside of 1-9.
+    pub fn try_with_values(rows: impl Into<[ [ Option<u8>; 9 ]; 9 ]>) -> Result<Self, InvalidReason> {
+        let rows: [ [ Option<u8>; 9 ]; 9 ] = rows.into();
+        for (y, row) in rows.iter().enumerate() {
+            for (x, cell) in row.iter().enumerate() {
+                if let Some(value) = cell {
+                    if !(1..=9).contains(value) { return Err(InvalidReason::InvalidValue { cell: (x, y), value: *value }); }
+                }
+            }
         }
+        Ok(Self::with_values(rows))
     }
 
     /// Constructor for succintly defining Sudoku's.
@@ -290,49 +1545,415 @@ impl Sudoku {
         // Create ourselves with that
         Self {
             rows,
+            givens : None,
+        }
+    }
+
+    /// Constructor for a Sudoku that computes the value of every cell with a function.
+    /// 
+    /// # Arguments
+    /// - `f`: A function that is called with the `(x, y)` coordinates of every cell (in row-major order) and returns its value.
+    /// 
+    /// # Returns
+    /// A new instance of Self with the values returned by `f` in the cells.
+    #[inline]
+    pub fn from_fn(mut f: impl FnMut(usize, usize) -> Option<u8>) -> Self {
+        let mut rows: [ [ Option<u8>; 9 ]; 9 ] = [ [ None; 9 ]; 9 ];
+        for (y, row) in rows.iter_mut().enumerate() {
+            for (x, cell) in row.iter_mut().enumerate() {
+                *cell = f(x, y);
+            }
+        }
+        Self::with_values(rows)
+    }
+
+    /// Constructor for a random Sudoku that is well-formed, but not necessarily complete (or even solvable).
+    /// 
+    /// This is useful as a quick fixture for (property) testing. Every cell is filled with a random [candidate](Sudoku::candidates()) with a probability of one half, visiting the cells in a random order.
+    /// 
+    /// # Arguments
+    /// - `rng`: The random number generator to use.
+    /// 
+    /// # Returns
+    /// A new, [well-formed](Sudoku::well_formed()) instance of Self.
+    #[cfg(feature = "std")]
+    pub fn fill_random_valid(rng: &mut impl Rng) -> Self {
+        let mut cells: Vec<(usize, usize)> = (0..81).map(|i| (i % 9, i / 9)).collect();
+        cells.shuffle(rng);
+
+        // Place a non-conflicting digit in about half of them
+        let mut sudoku: Self = Self::empty();
+        for (x, y) in cells {
+            if !rng.gen_bool(0.5) { continue; }
+            if let Some(value) = sudoku.candidates(x, y).choose(rng) { sudoku.rows[y][x] = Some(*value); }
+        }
+        sudoku
+    }
+
+
+
+    /// Marks all currently filled-in cells as locked givens.
+    /// 
+    /// Locked givens are never overwritten by [`Sudoku::set()`], and [`Sudoku::cell_valid()`] rejects any other value for them (meaning solvers will leave them alone too).
+    #[inline]
+    pub fn lock_givens(&mut self) { self.givens = Some(self.rows.map(|r| r.map(|c| c.is_some()))); }
+
+    /// Returns which cells are locked givens, if any have been marked.
+    /// 
+    /// # Returns
+    /// A 9x9 grid of booleans that is true for every locked given, or [`None`] if [`Sudoku::lock_givens()`] has never been called.
+    #[inline]
+    pub fn givens(&self) -> Option<&[ [ bool; 9 ]; 9 ]> { self.givens.as_ref() }
+
+    /// Returns whether a particular cell is a locked given.
+    /// 
+    /// # Arguments
+    /// - `x`: The X-coordinate of the cell in the sudoku.
+    /// - `y`: The Y-coordinate of the cell in the sudoku.
+    /// 
+    /// # Returns
+    /// True if the cell is locked, or false otherwise (including if no givens have been marked).
+    #[inline]
+    pub fn is_given(&self, x: usize, y: usize) -> bool { self.givens.map(|g| g[y][x]).unwrap_or(false) }
+
+    /// Returns a copy of this puzzle with the first empty cells (in row-major order) filled-in from its solution.
+    /// 
+    /// # Arguments
+    /// - `solution`: The (possibly partial) solution to take the values from.
+    /// - `n`: The number of empty cells to fill-in.
+    /// 
+    /// # Returns
+    /// A tuple of the hinted [`Sudoku`] and the number of cells that were actually hinted. The latter is smaller than `n` if there are less than `n` empty cells.
+    pub fn with_hints(&self, solution: &Sudoku, n: usize) -> (Sudoku, usize) {
+        let mut hint: Sudoku = *self;
+        let mut given: usize = 0;
+        for (x, y) in (0..81).map(|i| (i % 9, i / 9)).filter(|(x, y)| self.rows[*y][*x].is_none()).take(n) {
+            hint.rows[y][x] = solution.rows[y][x];
+            given += 1;
+        }
+        (hint, given)
+    }
+
+    /// Returns a board with only the clues (givens) of this Sudoku.
+    /// 
+    /// If [givens have been locked](Sudoku::lock_givens()), only those cells are kept. Otherwise, all currently filled-in cells are kept, which, for an unsolved puzzle, are exactly its givens.
+    /// 
+    /// # Returns
+    /// A new [`Sudoku`] with only the clues filled-in. Useful as mask for a later solution (see [`Sudoku::masked()`]).
+    pub fn clue_mask(&self) -> Sudoku {
+        let mut mask: Sudoku = *self;
+        if let Some(givens) = &self.givens {
+            for (row, grow) in mask.rows.iter_mut().zip(givens.iter()) {
+                for (cell, given) in row.iter_mut().zip(grow.iter()) {
+                    if !given { *cell = None; }
+                }
+            }
+        }
+        mask
+    }
+
+    /// Sets the value of a particular cell, unless it is a locked given.
+    /// 
+    /// Note that this does not check whether the new value is valid.
+    /// 
+    /// # Arguments
+    /// - `x`: The X-coordinate of the cell in the sudoku.
+    /// - `y`: The Y-coordinate of the cell in the sudoku.
+    /// - `value`: The new value of the cell.
+    /// 
+    /// # Errors
+    /// This function errors with [`InvalidReason::LockedCell`] if the cell is a locked given.
+    pub fn set(&mut self, x: usize, y: usize, value: Option<u8>) -> Result<(), InvalidReason> {
+        if self.is_given(x, y) { return Err(InvalidReason::LockedCell { cell: (x, y) }); }
+        self.rows[y][x] = value;
+        Ok(())
+    }
+
+    /// Sets the value of a particular cell, but only if that is legal.
+    /// 
+    /// A placement is legal if the cell is not a locked given and the value does not conflict with any of the cell's peers (see [`Sudoku::candidates()`]).
+    /// 
+    /// # Arguments
+    /// - `x`: The X-coordinate of the cell in the sudoku.
+    /// - `y`: The Y-coordinate of the cell in the sudoku.
+    /// - `value`: The new value of the cell.
+    /// 
+    /// # Returns
+    /// True if the value was placed, or false if it was not (in which case the Sudoku is left untouched).
+    pub fn place_if_valid(&mut self, x: usize, y: usize, value: u8) -> bool {
+        if self.is_given(x, y) || !self.candidates(x, y).contains(&value) { return false; }
+        self.rows[y][x] = Some(value);
+        true
+    }
+
+
+
+    /// Returns an iterator over the rows of the Sudoku.
+    /// 
+    /// # Returns
+    /// An iterator that yields the rows from top to bottom.
+    #[inline]
+    pub fn rows_iter(&self) -> impl '_ + Iterator<Item = [ Option<u8>; 9 ]> { self.rows.iter().copied() }
+
+    /// Returns an iterator over the columns of the Sudoku.
+    /// 
+    /// # Returns
+    /// An iterator that yields the columns from left to right, each from top to bottom.
+    #[inline]
+    pub fn cols_iter(&self) -> impl '_ + Iterator<Item = [ Option<u8>; 9 ]> { (0..9).map(move |x| core::array::from_fn(|y| self.rows[y][x])) }
+
+
+
+    /// Returns how many percentage of cells is filled-in.
+    /// 
+    /// This does not consider well-formedness.
+    /// 
+    /// # Returns
+    /// A ratio of cells filled-in.
+    pub fn score(&self) -> f64 { self.rows.iter().map(|r| r.iter().filter(|c| c.is_some()).count()).sum::<usize>() as f64 / 81.0 }
+
+    /// Returns the number of cells that are still empty.
+    /// 
+    /// This does not consider well-formedness.
+    /// 
+    /// # Returns
+    /// The number of cells that are [`None`].
+    #[inline]
+    pub fn count_empty(&self) -> usize { self.rows.iter().flatten().filter(|c| c.is_none()).count() }
+
+    /// Returns the number of cells that are filled-in, i.e., the givens if this is a puzzle.
+    /// 
+    /// This does not consider well-formedness.
+    /// 
+    /// # Returns
+    /// The number of cells that are not [`None`].
+    #[inline]
+    pub fn givens_count(&self) -> usize { 81 - self.count_empty() }
+
+    /// Returns the number of constraint violations in the Sudoku.
+    /// 
+    /// This counts the pairs of cells with the same value in every row, column and box. Note that a pair sharing both a row (or column) and a box is thus counted twice.
+    /// 
+    /// # Returns
+    /// The total number of conflicting pairs. Zero means the Sudoku is well-formed.
+    pub fn conflict_count(&self) -> usize {
+        let mut count: usize = 0;
+        for i in 0..9 {
+            // Collect the i'th row, column and box
+            let row : [ Option<u8>; 9 ] = self.rows[i];
+            let col : [ Option<u8>; 9 ] = core::array::from_fn(|j| self.rows[j][i]);
+            let bx  : [ Option<u8>; 9 ] = core::array::from_fn(|j| self.rows[3 * (i / 3) + j / 3][3 * (i % 3) + j % 3]);
+
+            // Count the pairs with equal values in each of them
+            for unit in [ row, col, bx ] {
+                for (j, cell) in unit.iter().enumerate() {
+                    if cell.is_none() { continue; }
+                    count += unit[j + 1..].iter().filter(|c| *c == cell).count();
+                }
+            }
+        }
+        count
+    }
+
+    /// Returns whether a particular cell is valid and, if not, why not.
+    /// 
+    /// # Arguments
+    /// - `x`: The X-coordinate of the cell in the sudoku.
+    /// - `y`: The Y-coordinate of the cell in the sudoku.
+    /// - `value`: The value of the cell to check.
+    /// 
+    /// # Errors
+    /// This function errors with an [`InvalidReason`] explaining why the cell isn't valid if it wasn't.
+    pub fn cell_valid(&self, x: usize, y: usize, value: u8) -> Result<(), InvalidReason> {
+        // Only digits are valid at all
+        if !(1..=9).contains(&value) { return Err(InvalidReason::InvalidValue { cell: (x, y), value }); }
+        // Locked givens can only have the value they already have
+        if self.is_given(x, y) && self.rows[y][x] != Some(value) { return Err(InvalidReason::LockedCell { cell: (x, y) }); }
+
+        // Check if it's unique in the row direction so far
+        for i in 0..x {
+            if Some(value) == self.rows[y][i] { return Err(InvalidReason::RowConflict { cell: (x, y), conflict: (i, y) }); }
+        }
+        // Check if it's unique in the column direction so far
+        for i in 0..y {
+            if Some(value) == self.rows[i][x] { return Err(InvalidReason::ColConflict { cell: (x, y), conflict: (x, i) }); }
+        }
+        // Check if it's unique in this 3x3 grid
+        let xy_in_grid: usize = (y % 3) * 3 + (x % 3);
+        for i in 0..xy_in_grid {
+            // Convert back to new x & y
+            let x2: usize = 3 * (x / 3) + (i % 3);
+            let y2: usize = 3 * (y / 3) + (i / 3);
+
+            // Check if we are in conflict
+            if Some(value) == self.rows[y2][x2] { return Err(InvalidReason::BoxConflict { cell: (x, y), conflict: (x2, y2) }); }
+        }
+
+        // We made it this far so valid indeed
+        Ok(())
+    }
+
+    /// Returns the values that a particular cell may take given the rest of the Sudoku.
+    /// 
+    /// Unlike [`Sudoku::cell_valid()`], this considers all of the cell's peers in its row, column and box (but not the cell itself).
+    /// 
+    /// # Arguments
+    /// - `x`: The X-coordinate of the cell in the sudoku.
+    /// - `y`: The Y-coordinate of the cell in the sudoku.
+    /// 
+    /// # Returns
+    /// A list of the values (in ascending order) that do not conflict with any of the cell's peers. Peers with a value outside of 1-9 (see [`InvalidReason::InvalidValue`]) are ignored.
+    pub fn candidates(&self, x: usize, y: usize) -> Vec<u8> {
+        // Mark which values are taken by the peers
+        let mut taken: [ bool; 10 ] = [ false; 10 ];
+        let mut take = |value: Option<u8>| if let Some(v) = value { if (1..=9).contains(&v) { taken[v as usize] = true; } };
+        for i in 0..9 {
+            if i != x { take(self.rows[y][i]); }
+            if i != y { take(self.rows[i][x]); }
+            let (x2, y2): (usize, usize) = (3 * (x / 3) + i % 3, 3 * (y / 3) + i / 3);
+            if (x2, y2) != (x, y) { take(self.rows[y2][x2]); }
+        }
+
+        // The rest is what remains
+        (1..=9).filter(|v| !taken[*v as usize]).collect()
+    }
+
+    /// Repeatedly fills in empty cells that only have a single candidate left (i.e., naked singles).
+    /// 
+    /// # Returns
+    /// The number of cells that were filled-in.
+    pub fn fill_forced(&mut self) -> usize {
+        let mut filled: usize = 0;
+        loop {
+            // Find any forced cell
+            let mut changed: bool = false;
+            for y in 0..9 {
+                for x in 0..9 {
+                    if self.rows[y][x].is_some() { continue; }
+                    let candidates: Vec<u8> = self.candidates(x, y);
+                    if candidates.len() == 1 {
+                        self.rows[y][x] = Some(candidates[0]);
+                        filled += 1;
+                        changed = true;
+                    }
+                }
+            }
+
+            // Stop once nothing changes anymore
+            if !changed { return filled; }
+        }
+    }
+
+    /// Counts how many cells can be filled-in by repeatedly filling in naked singles, without changing this Sudoku.
+    /// 
+    /// This is a rough measure of difficulty: puzzles that mostly fall to this trivial propagation are easy, whereas harder ones get stuck early.
+    /// 
+    /// # Returns
+    /// The number of cells that [`Sudoku::fill_forced()`] would fill-in.
+    pub fn trivial_fill_count(&self) -> usize {
+        let mut sudoku: Sudoku = *self;
+        sudoku.fill_forced()
+    }
+
+    /// Exchanges all occurrences of two digits in the Sudoku.
+    /// 
+    /// Such a relabelling preserves whether the Sudoku is well-formed and/or finished, which makes it useful for canonicalization and generating variants.
+    /// 
+    /// # Arguments
+    /// - `a`: The first digit to swap.
+    /// - `b`: The second digit to swap.
+    pub fn swap_digits(&mut self, a: u8, b: u8) {
+        for cell in self.rows.iter_mut().flatten() {
+            if *cell == Some(a) { *cell = Some(b); }
+            else if *cell == Some(b) { *cell = Some(a); }
         }
     }
 
+    /// Checks whether this Sudoku agrees with another on all cells that are filled-in in both, e.g., to compare a partial result to a full solution.
+    /// 
+    /// # Arguments
+    /// - `other`: The other [`Sudoku`] to compare with.
+    /// 
+    /// # Returns
+    /// True if, for every cell where both Sudokus have a value, the values are the same, or false otherwise.
+    #[inline]
+    pub fn equals_ignoring_empties(&self, other: &Sudoku) -> bool {
+        self.rows.iter().flatten().zip(other.rows.iter().flatten()).all(|(a, b)| a.is_none() || b.is_none() || a == b)
+    }
 
+    /// Computes the Hamming distance between this Sudoku and another, e.g., to cluster the puzzles in a collection.
+    /// 
+    /// # Arguments
+    /// - `other`: The other [`Sudoku`] to compare with.
+    /// 
+    /// # Returns
+    /// The number of cells (out of 81) that differ, where a cell that is empty in one Sudoku but filled-in in the other counts as different too.
+    #[inline]
+    pub fn distance(&self, other: &Sudoku) -> usize {
+        self.rows.iter().flatten().zip(other.rows.iter().flatten()).filter(|(a, b)| a != b).count()
+    }
 
-    /// Returns how many percentage of cells is filled-in.
+    /// Finds the symmetries of the clue pattern of this Sudoku, i.e., which transformations map every filled-in cell onto another filled-in cell.
     /// 
-    /// This does not consider well-formedness.
+    /// Only the positions of the clues are considered, not their values.
     /// 
     /// # Returns
-    /// A ratio of cells filled-in.
-    pub fn score(&self) -> f64 { self.rows.iter().map(|r| r.iter().filter(|c| c.is_some()).count()).sum::<usize>() as f64 / 81.0 }
+    /// A list of the [`Symmetry`]s that the pattern has, in the order of [`Symmetry::ALL`]. Note that an empty (or full) Sudoku has all of them.
+    pub fn symmetry_type(&self) -> Vec<Symmetry> {
+        Symmetry::ALL.into_iter().filter(|sym| {
+            (0..81).map(|i| (i % 9, i / 9)).all(|(x, y)| {
+                let (x2, y2): (usize, usize) = sym.apply(x, y);
+                self.rows[y][x].is_some() == self.rows[y2][x2].is_some()
+            })
+        }).collect()
+    }
 
-    /// Returns whether a particular cell is valid and, if not, why not.
+    /// Checks whether this Sudoku is the same as another up to the symmetries of Sudoku.
+    /// 
+    /// The symmetries considered are relabelling the digits, transposing, permuting the bands and stacks, and permuting the rows (columns) within a band (stack). Any combination of these preserves whether the Sudoku is well-formed and/or finished, which makes this useful to recognise a puzzle regardless of its representation.
     /// 
     /// # Arguments
-    /// - `x`: The X-coordinate of the cell in the sudoku.
-    /// - `y`: The Y-coordinate of the cell in the sudoku.
-    /// - `value`: The value of the cell to check.
+    /// - `other`: The other [`Sudoku`] to compare with.
     /// 
-    /// # Errors
-    /// This function errors with an [`InvalidReason`] explaining why the cell isn't valid if it wasn't.
-    pub fn cell_valid(&self, x: usize, y: usize, value: u8) -> Result<(), InvalidReason> {
-        // Check if it's unique in the row direction so far
-        for i in 0..x {
-            if Some(value) == self.rows[y][i] { return Err(InvalidReason::RowConflict { cell: (x, y), conflict: (i, y) }); }
-        }
-        // Check if it's unique in the column direction so far
-        for i in 0..y {
-            if Some(value) == self.rows[i][x] { return Err(InvalidReason::ColConflict { cell: (x, y), conflict: (x, i) }); }
+    /// # Returns
+    /// True if `other` can be obtained from this Sudoku by applying any combination of the symmetries, or false otherwise.
+    pub fn equivalent(&self, other: &Sudoku) -> bool {
+        // The symmetries don't change the number of clues
+        let n_clues = |s: &Sudoku| -> usize { s.rows.iter().flatten().filter(|c| c.is_some()).count() };
+        if n_clues(self) != n_clues(other) { return false; }
+
+        // Try every row permutation (with or without transposing), and then search for a matching column permutation
+        let transposed: Sudoku = Sudoku::from_fn(|x, y| self.rows[x][y]);
+        let perms: Vec<[ usize; 9 ]> = band_permutations();
+        for base in [ &self.rows, &transposed.rows ] {
+            for perm in &perms {
+                let permuted: [ [ Option<u8>; 9 ]; 9 ] = core::array::from_fn(|y| base[perm[y]]);
+                if match_columns(&permuted, &other.rows, 0, &mut [ 0; 9 ], [ 0; 10 ], [ 0; 10 ]) { return true; }
+            }
         }
-        // Check if it's unique in this 3x3 grid
-        let xy_in_grid: usize = (y % 3) * 3 + (x % 3);
-        for i in 0..xy_in_grid {
-            // Convert back to new x & y
-            let x2: usize = 3 * (x / 3) + (i % 3);
-            let y2: usize = 3 * (y / 3) + (i / 3);
+        false
+    }
 
-            // Check if we are in conflict
-            if Some(value) == self.rows[y2][x2] { return Err(InvalidReason::BoxConflict { cell: (x, y), conflict: (x2, y2) }); }
+    /// Normalizes the values in the Sudoku by mapping any `Some(0)` to [`None`].
+    /// 
+    /// Hand-constructed Sudokus may use `0` to mean an empty cell, like the compact formats do. This makes sure such cells are really treated as empty.
+    /// 
+    /// # Errors
+    /// This function errors with an [`InvalidReason::InvalidValue`] if any cell has a value larger than 9. In that case, the Sudoku is left untouched.
+    pub fn normalize(&mut self) -> Result<(), InvalidReason> {
+        // Find any value that cannot be fixed first
+        for (y, row) in self.rows.iter().enumerate() {
+            for (x, cell) in row.iter().enumerate() {
+                if let Some(value) = cell {
+                    if *value > 9 { return Err(InvalidReason::InvalidValue { cell: (x, y), value: *value }); }
+                }
+            }
         }
 
-        // We made it this far so valid indeed
+        // Then map the zeroes
+        for cell in self.rows.iter_mut().flatten() {
+            if *cell == Some(0) { *cell = None; }
+        }
         Ok(())
     }
 
@@ -378,6 +1999,71 @@ impl Sudoku {
         Ok(())
     }
 
+    /// Checks a single row for duplicate values.
+    /// 
+    /// # Arguments
+    /// - `i`: The index of the row to check (`0..9`).
+    /// 
+    /// # Errors
+    /// This function errors with an [`InvalidReason::RowConflict`] if two cells in the row have the same value.
+    /// 
+    /// # Panics
+    /// This function panics if `i` is not a valid row index.
+    #[inline]
+    pub fn row_valid(&self, i: usize) -> Result<(), InvalidReason> {
+        self.unit_valid((0..9).map(|x| (x, i)), |cell, conflict| InvalidReason::RowConflict { cell, conflict })
+    }
+
+    /// Checks a single column for duplicate values.
+    /// 
+    /// # Arguments
+    /// - `i`: The index of the column to check (`0..9`).
+    /// 
+    /// # Errors
+    /// This function errors with an [`InvalidReason::ColConflict`] if two cells in the column have the same value.
+    /// 
+    /// # Panics
+    /// This function panics if `i` is not a valid column index.
+    #[inline]
+    pub fn col_valid(&self, i: usize) -> Result<(), InvalidReason> {
+        self.unit_valid((0..9).map(|y| (i, y)), |cell, conflict| InvalidReason::ColConflict { cell, conflict })
+    }
+
+    /// Checks a single 3x3 box for duplicate values.
+    /// 
+    /// # Arguments
+    /// - `i`: The index of the box to check (`0..9`), where boxes are numbered in row-major order.
+    /// 
+    /// # Errors
+    /// This function errors with an [`InvalidReason::BoxConflict`] if two cells in the box have the same value.
+    /// 
+    /// # Panics
+    /// This function panics if `i` is not a valid box index.
+    #[inline]
+    pub fn box_valid(&self, i: usize) -> Result<(), InvalidReason> {
+        self.unit_valid((0..9).map(|j| (3 * (i % 3) + j % 3, 3 * (i / 3) + j / 3)), |cell, conflict| InvalidReason::BoxConflict { cell, conflict })
+    }
+
+    /// Checks a single unit (i.e., row, column or box) for duplicate values.
+    /// 
+    /// # Arguments
+    /// - `cells`: The coordinates of the cells in the unit.
+    /// - `reason`: Constructs the [`InvalidReason`] for a conflict between a cell and an earlier cell in the unit.
+    /// 
+    /// # Errors
+    /// This function errors with the `reason` for the first cell that has the same value as an earlier one.
+    fn unit_valid(&self, cells: impl Iterator<Item = (usize, usize)>, reason: impl Fn((usize, usize), (usize, usize)) -> InvalidReason) -> Result<(), InvalidReason> {
+        let mut seen: [ Option<(usize, usize)>; 9 ] = [ None; 9 ];
+        for (x, y) in cells {
+            // Only consider cells with values (ignoring out-of-range ones, such as zeroes; see `Sudoku::normalize()`)
+            let Some(value): Option<u8> = self.rows[y][x].filter(|value| (1..=9).contains(value)) else { continue; };
+            let slot: &mut Option<(usize, usize)> = &mut seen[value as usize - 1];
+            if let Some(conflict) = slot { return Err(reason((x, y), *conflict)); }
+            *slot = Some((x, y));
+        }
+        Ok(())
+    }
+
 
 
     /// Returns whether a particular cell is valid.
@@ -410,31 +2096,220 @@ impl Sudoku {
     #[inline]
     pub fn is_finished(&self) -> bool { self.finished().is_ok() }
 
+    /// Returns whether the Sudoku is empty.
+    /// 
+    /// # Returns
+    /// True if none of the cells are filled-in, or false otherwise.
+    #[inline]
+    pub fn is_empty(&self) -> bool { self.rows.iter().all(|r| r.iter().all(|c| c.is_none())) }
+
+    /// Returns whether the Sudoku is full.
+    /// 
+    /// This is like [finished](Sudoku::is_finished()), except that the filled-in values do not have to be valid.
+    /// 
+    /// # Returns
+    /// True if all of the cells are filled-in, or false otherwise.
+    #[inline]
+    pub fn is_full(&self) -> bool { self.rows.iter().all(|r| r.iter().all(|c| c.is_some())) }
+
+    /// Returns whether the Sudoku is obviously unsolvable, i.e., whether any empty cell has no [candidates](Sudoku::candidates()) left.
+    /// 
+    /// This is a cheap check that does not allocate, meant as a pre-filter before actually solving. A Sudoku without obvious contradictions may still be unsolvable.
+    /// 
+    /// # Returns
+    /// True if some empty cell cannot take any value, or false otherwise.
+    pub fn has_obvious_contradiction(&self) -> bool {
+        const FULL: u16 = 0b11_1111_1110;
+
+        // Collect the values taken in every row, column and box as bitmasks (ignoring values outside of the 1-9 range)
+        let (mut rows, mut cols, mut boxes): ([ u16; 9 ], [ u16; 9 ], [ u16; 9 ]) = ([ 0; 9 ], [ 0; 9 ], [ 0; 9 ]);
+        for (y, row) in self.rows.iter().enumerate() {
+            for (x, cell) in row.iter().enumerate() {
+                if let Some(v) = cell.filter(|v| (1..=9).contains(v)) {
+                    rows[y] |= 1 << v;
+                    cols[x] |= 1 << v;
+                    boxes[3 * (y / 3) + x / 3] |= 1 << v;
+                }
+            }
+        }
+
+        // Then an empty cell is contradictory if its peers take all values
+        (0..81).any(|i| {
+            let (x, y): (usize, usize) = (i % 9, i / 9);
+            self.rows[y][x].is_none() && (rows[y] | cols[x] | boxes[3 * (y / 3) + x / 3]) & FULL == FULL
+        })
+    }
+
+
+
+    /// Displays the Sudoku on a single line of 81 cells (in row-major order), where `.` means an empty cell.
+    /// 
+    /// # Returns
+    /// A [`SudokuCompactFormatter`] that can format the Sudoku on one line.
+    #[inline]
+    pub fn compact(&self) -> SudokuCompactFormatter<'_> { SudokuCompactFormatter { sudoku: self } }
+
+    /// Displays the Sudoku next to another one, sharing their rows.
+    /// 
+    /// This is useful to compare a puzzle with its solution.
+    /// 
+    /// # Arguments
+    /// - `other`: The other [`Sudoku`] to show on the right.
+    /// 
+    /// # Returns
+    /// A [`SudokuSideBySideFormatter`] that can format both Sudokus.
+    #[inline]
+    pub fn side_by_side<'s, 'o>(&'s self, other: &'o Sudoku) -> SudokuSideBySideFormatter<'s, 'o> { SudokuSideBySideFormatter { left: self, right: other } }
 
+    /// Displays the Sudoku with the [candidates](Sudoku::candidates()) of every empty cell, i.e., its pencil marks.
+    /// 
+    /// By default, all candidates are shown, but this can be limited with [`SudokuCandidateFormatter::max_candidates()`].
+    /// 
+    /// # Returns
+    /// A [`SudokuCandidateFormatter`] that can format this Sudoku.
+    #[inline]
+    pub fn pencil_marks(&self) -> SudokuCandidateFormatter<'_> { SudokuCandidateFormatter { sudoku: self, max: None } }
 
     /// Displays the Sudoku with ANSI colours.
     /// 
     /// # Returns
     /// A [`SudokuColourFormatter`] that can format the Sudoku with colours.
+    #[cfg(feature = "std")]
     #[inline]
     pub fn coloured(&self) -> SudokuColourFormatter { SudokuColourFormatter { sudoku: self } }
 
     /// Displays the Sudoku with ANSI colours and a mask.
     /// 
-    /// The mask is the original Sudoku so it can be highlighted which parts are 'fixed' and which are 'solved'.
+    /// The mask is the original Sudoku so it can be highlighted which parts are 'fixed' and which are 'solved'. Cells that are still empty (e.g., in a best-effort partial solution) are shown as a dim dot.
     /// 
     /// # Arguments
     /// - `mask`: The mask [`Sudoku`] to apply.
     /// 
     /// # Returns
     /// A [`SudokuMaskFormatter`] that can format the Sudoku with colours.
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn masked<'s, 'm>(&'s self, mask: &'m Sudoku) -> SudokuMaskFormatter<'s, 'm> { SudokuMaskFormatter { sudoku: self, mask, given: Style::new().bold().blue(), colour: Style::new().bold(), legend: false, force: None } }
+
+    /// Displays this Sudoku as the solution to a puzzle, with ANSI colours.
+    /// 
+    /// Unlike [`Sudoku::masked()`], the givens are always taken from the puzzle and shown in the normal style, whereas the cells solved in this Sudoku are shown in green. Cells that neither fills in are shown as a dim dot.
+    /// 
+    /// # Arguments
+    /// - `puzzle`: The puzzle [`Sudoku`] that this Sudoku (partially) solves.
+    /// 
+    /// # Returns
+    /// A [`SudokuSolutionFormatter`] that can format the Sudoku with colours.
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn solution_view<'s, 'p>(&'s self, puzzle: &'p Sudoku) -> SudokuSolutionFormatter<'s, 'p> { SudokuSolutionFormatter { solution: self, puzzle, legend: false, force: None } }
+
+    /// Serializes the Sudoku as a compact string of 81 digits, where `0` means an empty cell.
+    /// 
+    /// This is the inverse of parsing a Sudoku with [`Sudoku::from_str()`].
+    /// 
+    /// # Returns
+    /// A new [`String`] with the cells in row-major order.
+    #[inline]
+    pub fn to_compact_string(&self) -> String { self.to_compact_ordered(CellOrder::RowMajor) }
+
+    /// Serializes the Sudoku as a compact string of 81 digits in the given order, where `0` means an empty cell.
+    /// 
+    /// This is the inverse of parsing a Sudoku with [`Sudoku::from_compact_ordered()`].
+    /// 
+    /// # Arguments
+    /// - `order`: The [`CellOrder`] in which to write the cells.
+    /// 
+    /// # Returns
+    /// A new [`String`] with the cells in the given order.
+    pub fn to_compact_ordered(&self, order: CellOrder) -> String {
+        (0..81).map(|i| {
+            let (x, y): (usize, usize) = order.coords(i);
+            (b'0' + self.rows[y][x].unwrap_or(0)) as char
+        }).collect()
+    }
+
+    /// Parses a Sudoku from a compact string of 81 cells in the given order, where either `0` or `.` means an empty cell.
+    /// 
+    /// # Arguments
+    /// - `s`: The string to parse. Leading and trailing whitespace is ignored.
+    /// - `order`: The [`CellOrder`] in which the cells are written.
+    /// 
+    /// # Returns
+    /// A new Sudoku with the parsed cells.
+    /// 
+    /// # Errors
+    /// This function errors if the string did not have exactly 81 cells or if any of them was not a digit or `.`.
+    pub fn from_compact_ordered(s: &str, order: CellOrder) -> Result<Self, CompactParseError> {
+        let s: &str = s.trim();
+
+        // Assert the length
+        let n_cells: usize = s.chars().count();
+        if n_cells != 81 { return Err(CompactParseError::IncorrectLength { got: n_cells }); }
+
+        // Parse the cells
+        let mut rows: [ [ Option<u8>; 9 ]; 9 ] = [ [ None; 9 ]; 9 ];
+        for (i, c) in s.chars().enumerate() {
+            let (x, y): (usize, usize) = order.coords(i);
+            rows[y][x] = match c {
+                '0' | '.'   => None,
+                '1'..='9'   => Some(c as u8 - b'0'),
+                c           => { return Err(CompactParseError::IllegalCellChar { cell: i + 1, got: c }); },
+            };
+        }
+        Ok(Self::with_values(rows))
+    }
+
+    /// Serializes the Sudoku to a fixed binary format of 81 bytes (one per cell, in row-major order), where `0` means an empty cell.
+    /// 
+    /// This is the inverse of [`Sudoku::from_bytes()`].
+    /// 
+    /// # Returns
+    /// An array with the value of every cell.
     #[inline]
-    pub fn masked<'s, 'm>(&'s self, mask: &'m Sudoku) -> SudokuMaskFormatter<'s, 'm> { SudokuMaskFormatter { sudoku: self, mask, colour: Style::new().bold() } }
+    pub fn to_bytes(&self) -> [ u8; 81 ] { core::array::from_fn(|i| self.rows[i / 9][i % 9].unwrap_or(0)) }
+
+    /// Deserializes a Sudoku from the fixed binary format of 81 bytes written by [`Sudoku::to_bytes()`].
+    /// 
+    /// # Arguments
+    /// - `bytes`: The value of every cell (in row-major order), where `0` means an empty cell.
+    /// 
+    /// # Returns
+    /// A new Sudoku with the given values.
+    /// 
+    /// # Errors
+    /// This function errors with an [`InvalidReason::InvalidValue`] if any of the bytes is larger than 9.
+    pub fn from_bytes(bytes: &[ u8; 81 ]) -> Result<Self, InvalidReason> {
+        let mut rows: [ [ Option<u8>; 9 ]; 9 ] = [ [ None; 9 ]; 9 ];
+        for (i, b) in bytes.iter().enumerate() {
+            rows[i / 9][i % 9] = match b {
+                0     => None,
+                1..=9 => Some(*b),
+                _     => { return Err(InvalidReason::InvalidValue { cell: (i % 9, i / 9), value: *b }); },
+            };
+        }
+        Ok(Self::with_values(rows))
+    }
+
+    /// Converts the Sudoku to a [`CandidateGrid`], e.g., as the entry point for logical solving.
+    /// 
+    /// # Returns
+    /// A new [`CandidateGrid`] where filled cells are a singleton set of their value, and empty cells have all of their [candidates](Sudoku::candidates()). Cells with a value outside of 1-9 get an empty set.
+    pub fn to_candidates(&self) -> CandidateGrid {
+        CandidateGrid {
+            rows : core::array::from_fn(|y| core::array::from_fn(|x| match self.rows[y][x] {
+                Some(v) if (1..=9).contains(&v) => 1 << v,
+                Some(_)                         => 0,
+                None                            => self.candidates(x, y).into_iter().fold(0, |mask, v| mask | (1 << v)),
+            })),
+        }
+    }
 
     /// Renders the Sudoku as a ratatui [`Table`] widget.
     /// 
     /// # Returns
     /// A new [`Table`] widget instance that will draw the current Sudoku state when rendered.
+    #[cfg(feature = "std")]
     #[inline]
     pub fn render(&self) -> Table {
         Table::new((0..9).map(|i| Row::new(self.rows[i].iter().map(|v| if let Some(v) = v { format!("{v}") } else { " ".into() }))))
@@ -473,3 +2348,210 @@ impl Display for Sudoku {
         Ok(())
     }
 }
+impl TryFrom<Vec<Vec<Option<u8>>>> for Sudoku {
+    type Error = VecConversionError;
+
+    /// Converts rows of cells to a Sudoku, checking that there are exactly 9x9 of them and that all values are in range.
+    fn try_from(value: Vec<Vec<Option<u8>>>) -> Result<Self, Self::Error> {
+        if value.len() != 9 { return Err(VecConversionError::IncorrectRowCount { got: value.len() }); }
+        let mut rows: [ [ Option<u8>; 9 ]; 9 ] = [ [ None; 9 ]; 9 ];
+        for (y, row) in value.into_iter().enumerate() {
+            rows[y] = row.try_into().map_err(|row: Vec<Option<u8>>| VecConversionError::IncorrectRowLength { row: y, got: row.len() })?;
+        }
+        Self::try_with_values(rows).map_err(|err| VecConversionError::InvalidValue { err })
+    }
+}
+impl FromStr for Sudoku {
+    type Err = CompactParseError;
+
+    /// Parses a Sudoku from a compact string of 81 cells in row-major order, where either `0` or `.` means an empty cell.
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> { Self::from_compact_ordered(s, CellOrder::RowMajor) }
+}
+
+
+
+/// Represents a Sudoku where every cell holds a set of candidate values instead of a single one.
+/// 
+/// This is the model for logical solving. Every set is stored as a bitmask, where bit `v` is set iff `v` is a candidate.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct CandidateGrid {
+    /// The candidate sets of the cells, as rows of bitmasks.
+    pub rows : [ [ u16; 9 ]; 9 ],
+}
+impl CandidateGrid {
+    /// Returns the candidates of the given cell.
+    /// 
+    /// # Arguments
+    /// - `x`: The X-coordinate of the cell in the grid.
+    /// - `y`: The Y-coordinate of the cell in the grid.
+    /// 
+    /// # Returns
+    /// A list of the candidate values (in ascending order) of the cell.
+    #[inline]
+    pub fn candidates(&self, x: usize, y: usize) -> Vec<u8> { (1..=9).filter(|v| self.contains(x, y, *v)).collect() }
+
+    /// Checks whether a value is a candidate for the given cell.
+    /// 
+    /// # Arguments
+    /// - `x`: The X-coordinate of the cell in the grid.
+    /// - `y`: The Y-coordinate of the cell in the grid.
+    /// - `value`: The value to check.
+    /// 
+    /// # Returns
+    /// True if `value` is in the cell's candidate set, or false otherwise.
+    #[inline]
+    pub fn contains(&self, x: usize, y: usize, value: u8) -> bool { value <= 9 && self.rows[y][x] & (1 << value) != 0 }
+
+    /// Returns the value of the given cell if it's decided, i.e., only has a single candidate left.
+    /// 
+    /// # Arguments
+    /// - `x`: The X-coordinate of the cell in the grid.
+    /// - `y`: The Y-coordinate of the cell in the grid.
+    /// 
+    /// # Returns
+    /// The only candidate of the cell, or [`None`] if it has zero or multiple candidates.
+    #[inline]
+    pub fn single(&self, x: usize, y: usize) -> Option<u8> {
+        let mask: u16 = self.rows[y][x];
+        if mask.count_ones() == 1 { Some(mask.trailing_zeros() as u8) } else { None }
+    }
+}
+
+
+
+/// Wraps a [`Sudoku`] that is being edited, keeping track of its validity incrementally.
+/// 
+/// Instead of re-checking the whole Sudoku after every edit (as [`Sudoku::well_formed()`] does), it keeps a count and a presence bitmask of every digit in every row, column and box. These are updated by every [`SudokuSession::set()`], such that both edits and conflict checks take constant time.
+#[derive(Clone, Debug)]
+pub struct SudokuSession {
+    /// The Sudoku being edited.
+    sudoku    : Sudoku,
+    /// How often every digit occurs in every unit, where units `0..9` are the rows, `9..18` the columns and `18..27` the boxes.
+    counts    : [ [ u8; 10 ]; 27 ],
+    /// Which digits occur in every unit, where bit `v` is set iff `v` occurs at least once.
+    masks     : [ u16; 27 ],
+    /// The number of surplus digits over all units (i.e., every digit after the first in a unit counts as a conflict).
+    conflicts : usize,
+    /// The number of cells with a value outside of the 1-9 range.
+    invalid   : usize,
+}
+impl SudokuSession {
+    /// Constructor for the SudokuSession.
+    /// 
+    /// # Arguments
+    /// - `sudoku`: The [`Sudoku`] to edit. Its locked givens (if any) are respected.
+    /// 
+    /// # Returns
+    /// A new instance of Self.
+    pub fn new(sudoku: Sudoku) -> Self {
+        let mut session: Self = Self { sudoku: Sudoku { rows: [ [ None; 9 ]; 9 ], ..sudoku }, counts: [ [ 0; 10 ]; 27 ], masks: [ 0; 27 ], conflicts: 0, invalid: 0 };
+        for i in 0..81 {
+            if let Some(value) = sudoku.rows[i / 9][i % 9] { session.add(i % 9, i / 9, value); }
+        }
+        session
+    }
+
+    /// Returns the units (row, column and box) that a cell is part of.
+    #[inline]
+    fn units(x: usize, y: usize) -> [ usize; 3 ] { [ y, 9 + x, 18 + 3 * (y / 3) + x / 3 ] }
+
+    /// Places a value in an empty cell, updating the bookkeeping.
+    fn add(&mut self, x: usize, y: usize, value: u8) {
+        self.sudoku.rows[y][x] = Some(value);
+        if !(1..=9).contains(&value) { self.invalid += 1; return; }
+        for unit in Self::units(x, y) {
+            if self.counts[unit][value as usize] > 0 { self.conflicts += 1; }
+            self.counts[unit][value as usize] += 1;
+            self.masks[unit] |= 1 << value;
+        }
+    }
+
+    /// Empties a cell, updating the bookkeeping.
+    fn remove(&mut self, x: usize, y: usize) {
+        let Some(value) = self.sudoku.rows[y][x].take() else { return; };
+        if !(1..=9).contains(&value) { self.invalid -= 1; return; }
+        for unit in Self::units(x, y) {
+            self.counts[unit][value as usize] -= 1;
+            if self.counts[unit][value as usize] > 0 { self.conflicts -= 1; } else { self.masks[unit] &= !(1 << value); }
+        }
+    }
+
+
+
+    /// Sets the value of a particular cell, unless it is a locked given.
+    /// 
+    /// Like [`Sudoku::set()`], this does not refuse values that conflict, but it does keep track of them.
+    /// 
+    /// # Arguments
+    /// - `x`: The X-coordinate of the cell in the sudoku.
+    /// - `y`: The Y-coordinate of the cell in the sudoku.
+    /// - `value`: The new value of the cell.
+    /// 
+    /// # Errors
+    /// This function errors with [`InvalidReason::LockedCell`] if the cell is a locked given.
+    pub fn set(&mut self, x: usize, y: usize, value: Option<u8>) -> Result<(), InvalidReason> {
+        if self.sudoku.is_given(x, y) { return Err(InvalidReason::LockedCell { cell: (x, y) }); }
+        self.remove(x, y);
+        if let Some(value) = value { self.add(x, y, value); }
+        Ok(())
+    }
+
+    /// Empties a particular cell, unless it is a locked given.
+    /// 
+    /// # Arguments
+    /// - `x`: The X-coordinate of the cell in the sudoku.
+    /// - `y`: The Y-coordinate of the cell in the sudoku.
+    /// 
+    /// # Errors
+    /// This function errors with [`InvalidReason::LockedCell`] if the cell is a locked given.
+    #[inline]
+    pub fn clear(&mut self, x: usize, y: usize) -> Result<(), InvalidReason> { self.set(x, y, None) }
+
+
+
+    /// Checks whether a value can be placed in a cell without conflicting with any of its peers.
+    /// 
+    /// # Arguments
+    /// - `x`: The X-coordinate of the cell in the sudoku.
+    /// - `y`: The Y-coordinate of the cell in the sudoku.
+    /// - `value`: The value to check.
+    /// 
+    /// # Returns
+    /// True if `value` is in the 1-9 range and none of the cell's peers has it (ignoring the cell itself), or false otherwise.
+    pub fn is_cell_valid(&self, x: usize, y: usize, value: u8) -> bool {
+        if !(1..=9).contains(&value) { return false; }
+        let own: u8 = if self.sudoku.rows[y][x] == Some(value) { 1 } else { 0 };
+        Self::units(x, y).into_iter().all(|unit| self.masks[unit] & (1 << value) == 0 || self.counts[unit][value as usize] == own)
+    }
+
+    /// Returns whether the Sudoku is well-formed, i.e., has no conflicting cells (see [`Sudoku::is_well_formed()`]).
+    /// 
+    /// # Returns
+    /// True if there are no conflicts, or false otherwise.
+    #[inline]
+    pub fn is_well_formed(&self) -> bool { self.conflicts == 0 && self.invalid == 0 }
+
+    /// Returns the number of conflicts in the Sudoku.
+    /// 
+    /// # Returns
+    /// The number of surplus digits in all rows, columns and boxes. For example, a digit that occurs three times in a row counts as two conflicts.
+    #[inline]
+    pub fn conflicts(&self) -> usize { self.conflicts }
+
+
+
+    /// Returns the Sudoku being edited.
+    /// 
+    /// # Returns
+    /// A reference to the current state of the [`Sudoku`].
+    #[inline]
+    pub fn sudoku(&self) -> &Sudoku { &self.sudoku }
+
+    /// Ends the session.
+    /// 
+    /// # Returns
+    /// The edited [`Sudoku`].
+    #[inline]
+    pub fn into_sudoku(self) -> Sudoku { self.sudoku }
+}
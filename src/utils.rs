@@ -4,7 +4,7 @@
 //  Created:
 //    10 Aug 2023, 23:45:41
 //  Last edited:
-//    12 Aug 2023, 00:01:55
+//    16 Oct 2026, 12:38:48
 //  Auto updated?
 //    Yes
 // 
@@ -13,24 +13,120 @@
 // 
 
 use std::error::Error;
-use std::ffi::OsString;
+use std::ffi::{OsStr, OsString};
 use std::fmt::{Debug, Display, Formatter, Result as FResult};
 use std::fs::File;
-use std::io::{Read as _, Seek as _, SeekFrom};
+#[cfg(feature = "net")]
+use std::io::Cursor;
+use std::io::{BufRead as _, BufReader, Read, Write};
 use std::path::{Path, PathBuf};
 use std::str::FromStr as _;
 
+use quick_xml::Reader as XmlReader;
+use quick_xml::events::Event as XmlEvent;
 use unicode_segmentation::UnicodeSegmentation as _;
 
-use crate::spec::FileType;
-use crate::sudoku::Sudoku;
+use crate::spec::{FileType, SudokuMetadata};
+use crate::sudoku::{CompactParseError, Sudoku};
 
 
 /***** TESTS *****/
 #[cfg(test)]
 mod tests {
+    use crate::solvers::{ConstraintPropagationSolver, Solver as _};
     use super::*;
 
+
+    #[test]
+    fn test_load_json() {
+        // Load both styles
+        let sudoku: Sudoku = match load_sudoku_of_type("./tests/correct.json", FileType::Json) {
+            Ok(mut sudoku) => sudoku.swap_remove(0),
+            Err(err)       => { panic!("Failed to parse sudoku file './tests/correct.json': {}", err.pretty()); },
+        };
+        let compact: Sudoku = match load_sudoku_of_type("./tests/correct_compact.json", FileType::Json) {
+            Ok(mut sudoku) => sudoku.swap_remove(0),
+            Err(err)       => { panic!("Failed to parse sudoku file './tests/correct_compact.json': {}", err.pretty()); },
+        };
+
+        // Assert they are the same
+        assert_eq!(sudoku, compact);
+    }
+
+    #[test]
+    fn test_load_json_wrong_shape() {
+        // Load a Sudoku with only eight rows
+        let err: LoadError = match load_sudoku_of_type("./tests/eight_rows.json", FileType::Json) {
+            Ok(sudoku) => { panic!("Successfully parsed invalid sudoku file './tests/eight_rows.json' as:\n{}", sudoku[0]); },
+            Err(err)   => err,
+        };
+        println!("{}", err.pretty());
+
+        // Assert it failed to parse, and that it tells us why
+        assert!(matches!(err, LoadError::FileParse { ftype: FileType::Json, .. }));
+        assert!(err.source().unwrap().to_string().contains("expected an array of length 9"));
+    }
+
+    #[test]
+    fn test_load_csv() {
+        // Load the example with names
+        let sudokus: Vec<(String, Sudoku)> = load_sudoku_csv("./tests/named.csv").unwrap_or_else(|err| panic!("Failed to load sudoku file './tests/named.csv': {}", err.pretty()));
+        assert_eq!(sudokus.len(), 2);
+        assert_eq!(sudokus[0], ("easy".into(), load_sudoku("./tests/example.sdk").unwrap().swap_remove(0)));
+        assert_eq!(sudokus[1], ("hard, or \"difficult\"".into(), load_sudoku("./tests/example.sdm").unwrap().swap_remove(0)));
+
+        // Loading it normally drops the names
+        assert_eq!(load_sudoku("./tests/named.csv").unwrap(), sudokus.into_iter().map(|(_, sudoku)| sudoku).collect::<Vec<Sudoku>>());
+
+        // Broken rows are reported
+        assert!(matches!(parse_sudoku_csv(&mut "name,puzzle\nfoo".as_bytes()), Err(CsvError::MissingColumn { line: 2 })));
+        assert!(matches!(parse_sudoku_csv(&mut "name,puzzle\n\"foo,123".as_bytes()), Err(CsvError::UnterminatedQuote { line: 2 })));
+        assert!(matches!(parse_sudoku_csv(&mut "name,puzzle\nfoo,123".as_bytes()), Err(CsvError::IllegalPuzzle { line: 2, err: CompactParseError::IncorrectLength { got: 3 } })));
+    }
+
+    #[test]
+    fn test_load_parse_error() {
+        // Parse errors can be matched per format
+        let err: LoadError = parse_sudoku_of_type(&mut std::io::Cursor::new("123x"), Path::new("broken.sdm"), FileType::SudokuPuzzleCollection, None).unwrap_err();
+        println!("{}", err.pretty());
+        assert!(matches!(err, LoadError::FileParse {
+            ftype : FileType::SudokuPuzzleCollection,
+            err   : ParseError::SudokuPuzzleCollection { err: SudokuPuzzleCollectionError::TooFewCells { line: 1, got: 4 } },
+            ..
+        }));
+        let err: LoadError = parse_sudoku_of_type(&mut std::io::Cursor::new(format!("{}x", "0".repeat(80))), Path::new("broken.sdm"), FileType::SudokuPuzzleCollection, None).unwrap_err();
+        assert!(matches!(err, LoadError::FileParse { err: ParseError::SudokuPuzzleCollection { err: SudokuPuzzleCollectionError::IllegalCellChar { line: 1, cell: 81, .. } }, .. }));
+        let err: LoadError = parse_sudoku_of_type(&mut std::io::Cursor::new(format!("{}\n{}", "0".repeat(81), "0".repeat(80))), Path::new("broken.sdm"), FileType::SudokuPuzzleCollection, None).unwrap_err();
+        assert!(matches!(err, LoadError::FileParse { err: ParseError::SudokuPuzzleCollection { err: SudokuPuzzleCollectionError::TooFewCells { line: 2, got: 80 } }, .. }));
+        let err: LoadError = parse_sudoku_of_type(&mut std::io::Cursor::new("0".repeat(82)), Path::new("broken.sdm"), FileType::SudokuPuzzleCollection, None).unwrap_err();
+        assert!(matches!(err, LoadError::FileParse { err: ParseError::SudokuPuzzleCollection { err: SudokuPuzzleCollectionError::TooManyCells { line: 1, got: 82 } }, .. }));
+
+        // Compact JSON strings report the compact parse error
+        let err: LoadError = parse_sudoku_of_type(&mut std::io::Cursor::new("\"123\""), Path::new("broken.json"), FileType::Json, None).unwrap_err();
+        assert!(matches!(err, LoadError::FileParse { err: ParseError::JsonCompact { err: CompactParseError::IncorrectLength { got: 3 } }, .. }));
+    }
+
+    #[test]
+    fn test_load_unknown_extension() {
+        // Aliases map to the same type as their main extension
+        assert_eq!(FileType::from_ext(OsStr::new("sudoku")), Some(FileType::SudokuPuzzle));
+        assert_eq!(FileType::from_ext(OsStr::new("TEXT")), Some(FileType::PlainGrid));
+        assert_eq!(FileType::from_ext(OsStr::new("markdown")), Some(FileType::Markdown));
+        for (ext, ftype) in FileType::EXTENSIONS {
+            assert_eq!(FileType::from_ext(OsStr::new(ext)), Some(ftype));
+        }
+
+        // Unknown extensions list the supported ones
+        let err: LoadError = load_sudoku("./tests/example.foo").unwrap_err();
+        assert!(matches!(err, LoadError::UnknownExtension { .. }));
+        let msg: String = err.to_string();
+        println!("{msg}");
+        assert!(msg.contains("'foo'"));
+        for (ext, _) in FileType::EXTENSIONS {
+            assert!(msg.contains(&format!("'.{ext}'")));
+        }
+    }
+
     #[test]
     fn test_load_sudoku_puzzle() {
         // Load the example
@@ -58,6 +154,57 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_load_sudoku_with_metadata() {
+        let sudoku: Sudoku = load_sudoku("./tests/example.sdk").unwrap_or_else(|err| panic!("Failed to parse sudoku file './tests/example.sdk': {}", err.pretty())).swap_remove(0);
+        let (loaded, meta): (Sudoku, Option<SudokuMetadata>) = match load_sudoku_with_metadata("./tests/example.sdk") {
+            Ok(mut sudokus) => { assert_eq!(sudokus.len(), 1); sudokus.swap_remove(0) },
+            Err(err)        => { panic!("Failed to parse sudoku file './tests/example.sdk': {}", err.pretty()); },
+        };
+        assert_eq!(loaded, sudoku);
+        assert_eq!(meta, Some(SudokuMetadata {
+            author      : Some("Ruud".into()),
+            description : Some("A random puzzle created by SudoCue".into()),
+            comment     : Some("Just start plugging in the numbers".into()),
+            date        : Some("03-08-2006".into()),
+            source      : Some("SudoCue".into()),
+            level       : Some("Easy".into()),
+            url         : Some("http://www.sudocue.net/fileformats.php".into()),
+        }));
+
+        // Formats without metadata have none
+        let sudokus: Vec<(Sudoku, Option<SudokuMetadata>)> = load_sudoku_with_metadata("./tests/example.sdm").unwrap_or_else(|err| panic!("Failed to parse sudoku file './tests/example.sdm': {}", err.pretty()));
+        assert_eq!(sudokus.len(), 8);
+        assert!(sudokus.iter().all(|(_, meta)| meta.is_none()));
+    }
+
+    #[test]
+    fn test_load_plain_grid() {
+        // Load the example, which is the same as the Sudoku Puzzle one
+        let sudoku: Sudoku = load_sudoku("./tests/example.txt").unwrap_or_else(|err| panic!("Failed to parse sudoku file './tests/example.txt': {}", err.pretty())).swap_remove(0);
+        assert_eq!(sudoku, load_sudoku("./tests/example.sdk").unwrap_or_else(|err| panic!("Failed to parse sudoku file './tests/example.sdk': {}", err.pretty())).swap_remove(0));
+
+        // Metadata is not allowed
+        let err: LoadError = parse_sudoku_of_type(&mut std::io::Cursor::new("#ARuud\n"), Path::new("broken.txt"), FileType::PlainGrid, None).unwrap_err();
+        assert!(matches!(err, LoadError::FileParse { err: ParseError::PlainGrid { err: SudokuPuzzleError::IncorrectLength { line: 1, got: 6 } }, .. }));
+    }
+
+    #[test]
+    fn test_load_bom() {
+        // A BOM-prefixed Sudoku Puzzle file loads the same as without
+        let sudoku: Sudoku = load_sudoku("./tests/example_bom.sdk").unwrap_or_else(|err| panic!("Failed to parse sudoku file './tests/example_bom.sdk': {}", err.pretty())).swap_remove(0);
+        assert_eq!(sudoku, load_sudoku("./tests/example.sdk").unwrap_or_else(|err| panic!("Failed to parse sudoku file './tests/example.sdk': {}", err.pretty())).swap_remove(0));
+
+        // As do all other formats
+        for path in [ "./tests/correct.json", "./tests/correct_compact.json", "./tests/named.csv", "./tests/example.txt", "./tests/example.sdx", "./tests/example.sdm", "./tests/example_new.ss", "./tests/example_old.ss" ] {
+            let path: &Path = Path::new(path);
+            let ftype: FileType = FileType::from_ext(path.extension().unwrap()).unwrap();
+            let raw: String = format!("\u{FEFF}{}", std::fs::read_to_string(path).unwrap_or_else(|err| panic!("Failed to read '{}': {err}", path.display())));
+            let sudokus: Vec<Sudoku> = parse_sudoku_of_type(&mut std::io::Cursor::new(raw), path, ftype, None).unwrap_or_else(|err| panic!("Failed to parse BOM-prefixed '{}': {}", path.display(), err.pretty()));
+            assert_eq!(sudokus, load_sudoku(path).unwrap_or_else(|err| panic!("Failed to parse sudoku file '{}': {}", path.display(), err.pretty())));
+        }
+    }
+
     #[test]
     fn test_load_sudoku_puzzle_progress() {
         // Load the example
@@ -85,6 +232,33 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_load_sudoku_puzzle_progress_tabs() {
+        // Load the example and a version of it that uses tabs and multiple spaces
+        let sudoku: Sudoku = load_sudoku("./tests/example.sdx").unwrap_or_else(|err| panic!("Failed to parse sudoku file './tests/example.sdx': {}", err.pretty())).swap_remove(0);
+        let tabbed: Sudoku = load_sudoku("./tests/example_tabs.sdx").unwrap_or_else(|err| panic!("Failed to parse sudoku file './tests/example_tabs.sdx': {}", err.pretty())).swap_remove(0);
+
+        // Assert they are the same
+        assert_eq!(tabbed, sudoku);
+    }
+
+    #[test]
+    fn test_load_sudoku_puzzle_progress_commas() {
+        // Load the example and a version of it that separates its cells with commas
+        let sudoku: Sudoku = load_sudoku("./tests/example.sdx").unwrap_or_else(|err| panic!("Failed to parse sudoku file './tests/example.sdx': {}", err.pretty())).swap_remove(0);
+        let commas: Sudoku = load_sudoku("./tests/example_commas.sdx").unwrap_or_else(|err| panic!("Failed to parse sudoku file './tests/example_commas.sdx': {}", err.pretty())).swap_remove(0);
+
+        // Assert they are the same
+        assert_eq!(commas, sudoku);
+    }
+
+    #[test]
+    fn test_load_sudoku_puzzle_collection_semicolons() {
+        let sudokus: Vec<Sudoku> = load_sudoku("./tests/example.sdm").unwrap_or_else(|err| panic!("Failed to parse sudoku file './tests/example.sdm': {}", err.pretty()));
+        let separated: Vec<Sudoku> = load_sudoku("./tests/example_semicolons.sdm").unwrap_or_else(|err| panic!("Failed to parse sudoku file './tests/example_semicolons.sdm': {}", err.pretty()));
+        assert_eq!(separated, sudokus[..2]);
+    }
+
     #[test]
     fn test_load_sudoku_puzzle_collection() {
         // Load the example
@@ -109,6 +283,52 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_load_sudoku_max() {
+        // Only the first few are loaded
+        let sudokus: Vec<Sudoku> = load_sudoku("./tests/example.sdm").unwrap_or_else(|err| panic!("Failed to parse sudoku file './tests/example.sdm': {}", err.pretty()));
+        let first: Vec<Sudoku> = load_sudoku_max("./tests/example.sdm", Some(3)).unwrap_or_else(|err| panic!("Failed to parse sudoku file './tests/example.sdm': {}", err.pretty()));
+        assert_eq!(first, sudokus[..3]);
+        assert_eq!(load_sudoku_max("./tests/example.sdm", Some(100)).unwrap().len(), sudokus.len());
+        assert_eq!(load_sudoku_of_type_max("./tests/example.opensudoku", FileType::OpenSudoku, Some(1)).unwrap().len(), 1);
+
+        // Collections are not read further than needed, so a broken tail is never seen
+        let raw: String = format!("{}\n{}\nbroken\n", "0".repeat(81), "1".repeat(81));
+        assert_eq!(parse_sudoku_of_type(&mut std::io::Cursor::new(&raw), Path::new("tail.sdm"), FileType::SudokuPuzzleCollection, Some(2)).unwrap().len(), 2);
+        assert!(parse_sudoku_of_type(&mut std::io::Cursor::new(&raw), Path::new("tail.sdm"), FileType::SudokuPuzzleCollection, None).is_err());
+    }
+
+    #[test]
+    fn test_load_open_sudoku() {
+        // The games should match the first Sudokus of the collection example
+        let sdm: Vec<Sudoku> = load_sudoku("./tests/example.sdm").unwrap_or_else(|err| panic!("Failed to parse sudoku file './tests/example.sdm': {}", err.pretty()));
+        let sudokus: Vec<Sudoku> = match load_sudoku("./tests/example.opensudoku") {
+            Ok(sudokus) => sudokus,
+            Err(err)    => { panic!("Failed to parse sudoku file './tests/example.opensudoku': {}", err.pretty()); },
+        };
+        assert_eq!(sudokus, sdm[..3]);
+
+        // Games without data are rejected
+        let err: LoadError = parse_sudoku_of_type(&mut std::io::Cursor::new("<opensudoku><game /></opensudoku>"), Path::new("broken.xml"), FileType::OpenSudoku, None).unwrap_err();
+        assert!(matches!(err, LoadError::FileParse { err: ParseError::OpenSudoku { err: OpenSudokuError::MissingData { game: 1 } }, .. }));
+    }
+
+    #[test]
+    fn test_load_markdown() {
+        // The plain code blocks hold the SudoCue example and the first of the collection, while the Rust one is skipped
+        let sdk: Vec<Sudoku> = load_sudoku("./tests/example.sdk").unwrap_or_else(|err| panic!("Failed to parse sudoku file './tests/example.sdk': {}", err.pretty()));
+        let sdm: Vec<Sudoku> = load_sudoku("./tests/example.sdm").unwrap_or_else(|err| panic!("Failed to parse sudoku file './tests/example.sdm': {}", err.pretty()));
+        let sudokus: Vec<Sudoku> = match load_sudoku("./tests/example.md") {
+            Ok(sudokus) => sudokus,
+            Err(err)    => { panic!("Failed to parse sudoku file './tests/example.md': {}", err.pretty()); },
+        };
+        assert_eq!(sudokus, vec![ sdk[0], sdm[0] ]);
+
+        // Broken grids are reported with the line their block starts on
+        let err: LoadError = parse_sudoku_of_type(&mut std::io::Cursor::new("Hello\n\n```\n123\n```\n"), Path::new("broken.md"), FileType::Markdown, None).unwrap_err();
+        assert!(matches!(err, LoadError::FileParse { err: ParseError::Markdown { err: MarkdownError::IllegalGrid { line: 3, err: SudokuPuzzleError::IncorrectLength { line: 1, got: 3 } } }, .. }));
+    }
+
     #[test]
     fn test_load_simple_sudoku() {
         // Try the new example first
@@ -130,6 +350,16 @@ mod tests {
             sudoku,
             Sudoku::from_compact([ 0,6,0,1,0,4,0,5,0,0,0,8,3,0,5,6,0,0,2,0,0,0,0,0,0,0,1,8,0,0,4,0,7,0,0,6,0,0,6,0,0,0,3,0,0,7,0,0,9,0,1,0,0,4,5,0,0,0,0,0,0,0,2,0,0,7,2,0,6,9,0,0,0,4,0,5,0,8,0,7,0 ]),
         );
+
+        // Both are detected through their extension alone
+        assert_eq!(load_sudoku("./tests/example_new.ss").unwrap_or_else(|err| panic!("Failed to load sudoku file './tests/example_new.ss': {}", err.pretty())), load_sudoku_of_type("./tests/example_new.ss", FileType::SimpleSudokuNew).unwrap());
+        assert_eq!(load_sudoku("./tests/example_old.ss").unwrap_or_else(|err| panic!("Failed to load sudoku file './tests/example_old.ss': {}", err.pretty())), load_sudoku_of_type("./tests/example_old.ss", FileType::SimpleSudokuOld).unwrap());
+
+        // Errors are those of the detected style only
+        let err: LoadError = parse_sudoku_of_type(&mut std::io::Cursor::new("1..|...|7..\n.2.|..?|5..\n"), Path::new("broken.ss"), FileType::SimpleSudoku, None).unwrap_err();
+        assert!(matches!(err, LoadError::FileParse { err: ParseError::SimpleSudoku { err: SimpleSudokuError::New { .. } }, .. }));
+        let err: LoadError = parse_sudoku_of_type(&mut std::io::Cursor::new("X6X1X4X5X\nXX83X56X\n"), Path::new("broken.ss"), FileType::SimpleSudoku, None).unwrap_err();
+        assert!(matches!(err, LoadError::FileParse { err: ParseError::SimpleSudoku { err: SimpleSudokuError::Old { err: SimpleSudokuOldError::TooManyCols { line: 2 } } }, .. }));
     }
 
     #[test]
@@ -147,6 +377,52 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_solution_header() {
+        let sudoku: Sudoku = load_sudoku("./tests/example.sdk").unwrap_or_else(|err| panic!("Failed to load sudoku file './tests/example.sdk': {}", err.pretty())).swap_remove(0);
+        assert_eq!(sudoku.givens_count(), 36);
+        assert_eq!(solution_header("example", &sudoku), "Solution to Sudoku 'example' (36 givens):");
+        assert_eq!(solution_header("empty", &Sudoku::empty()), "Solution to Sudoku 'empty' (0 givens):");
+    }
+
+    #[test]
+    fn test_count_report() {
+        let sudokus: Vec<Sudoku> = load_sudoku("./tests/example.sdm").unwrap_or_else(|err| panic!("Failed to load sudoku file './tests/example.sdm': {}", err.pretty()));
+        assert_eq!(count_report("./tests/example.sdm", &sudokus), "Loaded 8 Sudoku(s) from './tests/example.sdm'");
+    }
+
+    #[test]
+    fn test_write_compact_solutions() {
+        let sudokus: Vec<Sudoku> = load_sudoku("./tests/example.sdm").unwrap_or_else(|err| panic!("Failed to load sudoku file './tests/example.sdm': {}", err.pretty()));
+        let solutions: Vec<Sudoku> = sudokus.iter().map(|sudoku| ConstraintPropagationSolver::new().run(*sudoku)).collect();
+
+        // Every solution is written as exactly one line of 81 cells
+        let mut raw: Vec<u8> = vec![];
+        write_compact_solutions(&solutions, &mut raw).unwrap_or_else(|err| panic!("Failed to write solutions: {err}"));
+        let raw: String = String::from_utf8(raw).unwrap();
+        assert_eq!(raw.lines().count(), solutions.len());
+        for (line, solution) in raw.lines().zip(&solutions) {
+            assert_eq!(line.len(), 81);
+            assert_eq!(&line.parse::<Sudoku>().unwrap(), solution);
+        }
+        assert!(raw.ends_with('\n'));
+    }
+
+    #[test]
+    fn test_write_simple_sudoku_new() {
+        // Load the example
+        let sudoku: Sudoku = load_sudoku("./tests/example_new.ss").unwrap_or_else(|err| panic!("Failed to load sudoku file './tests/example_new.ss': {}", err.pretty())).swap_remove(0);
+
+        // Write it, which should produce the same layout as the file itself
+        let mut raw: Vec<u8> = vec![];
+        write_simple_sudoku_new(&sudoku, &mut raw).unwrap_or_else(|err| panic!("Failed to write sudoku: {err}"));
+        assert_eq!(String::from_utf8_lossy(&raw), std::fs::read_to_string("./tests/example_new.ss").unwrap());
+
+        // Reading it back should produce the identical board
+        let reloaded: Sudoku = parse_simple_sudoku_new(&mut raw.as_slice()).unwrap_or_else(|err| panic!("Failed to reload written sudoku: {}", err.pretty())).swap_remove(0);
+        assert_eq!(reloaded, sudoku);
+    }
+
     #[test]
     fn test_load_simple_sudoku_old() {
         // Load the example
@@ -159,7 +435,83 @@ mod tests {
         assert_eq!(
             sudoku,
             Sudoku::from_compact([ 0,6,0,1,0,4,0,5,0,0,0,8,3,0,5,6,0,0,2,0,0,0,0,0,0,0,1,8,0,0,4,0,7,0,0,6,0,0,6,0,0,0,3,0,0,7,0,0,9,0,1,0,0,4,5,0,0,0,0,0,0,0,2,0,0,7,2,0,6,9,0,0,0,4,0,5,0,8,0,7,0 ]),
-        )
+        );
+
+        // Lowercase markers and a trailing newline are fine too, but zeroes are not
+        let raw: String = std::fs::read_to_string("./tests/example_old.ss").unwrap_or_else(|err| panic!("Failed to read './tests/example_old.ss': {err}"));
+        let lower: Vec<Sudoku> = parse_sudoku_of_type(&mut std::io::Cursor::new(format!("{}\n", raw.replace('X', "x"))), Path::new("lower.ss"), FileType::SimpleSudokuOld, None).unwrap_or_else(|err| panic!("Failed to parse lowercase sudoku: {}", err.pretty()));
+        assert_eq!(lower, vec![ sudoku ]);
+        let err: LoadError = parse_sudoku_of_type(&mut std::io::Cursor::new(raw.replacen('X', "0", 1)), Path::new("zero.ss"), FileType::SimpleSudokuOld, None).unwrap_err();
+        assert!(matches!(err, LoadError::FileParse { err: ParseError::SimpleSudokuOld { err: SimpleSudokuOldError::IllegalCellChar { line: 1, col: 1, .. } }, .. }));
+    }
+
+    #[test]
+    fn test_convert_sudoku() {
+        assert_eq!(converted_path("./tests/example.sdk", None, FileType::Json), PathBuf::from("./tests/example.json"));
+        assert_eq!(converted_path("./tests/example.sdm", Some(2), FileType::PlainGrid), PathBuf::from("./tests/example_3.txt"));
+        assert_eq!(converted_path("puzzle", None, FileType::SimpleSudokuNew), PathBuf::from("puzzle.ss"));
+
+        // Converting a puzzle to JSON reloads to the same board
+        let sudoku: Sudoku = load_sudoku("./tests/example.sdk").unwrap_or_else(|err| panic!("Failed to load sudoku file './tests/example.sdk': {}", err.pretty())).swap_remove(0);
+        let path: PathBuf = converted_path(std::env::temp_dir().join(format!("sudoku-solver-test-convert-{}.sdk", std::process::id())), None, FileType::Json);
+        save_sudoku_of_type(&sudoku, &path, FileType::Json).unwrap_or_else(|err| panic!("Failed to save sudoku file '{}': {}", path.display(), err.pretty()));
+        assert_eq!(load_sudoku(&path).unwrap_or_else(|err| panic!("Failed to load sudoku file '{}': {}", path.display(), err.pretty())), vec![ sudoku ]);
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_split_sudokus() {
+        let sudokus: Vec<Sudoku> = load_sudoku("./tests/example.sdm").unwrap_or_else(|err| panic!("Failed to parse sudoku file './tests/example.sdm': {}", err.pretty()));
+        let dir: PathBuf = std::env::temp_dir().join(format!("sudoku-solver-test-split-{}", std::process::id()));
+
+        // Every puzzle ends up in its own file, which loads to the same board
+        for ftype in [ FileType::SudokuPuzzle, FileType::PlainGrid, FileType::SimpleSudokuNew, FileType::Json ] {
+            let paths: Vec<PathBuf> = split_sudokus(&sudokus, &dir, ftype).unwrap_or_else(|err| panic!("Failed to split sudokus as {ftype}: {}", err.pretty()));
+            assert_eq!(paths.len(), 8);
+            for (path, sudoku) in paths.iter().zip(&sudokus) {
+                assert_eq!(load_sudoku_of_type(path, ftype).unwrap_or_else(|err| panic!("Failed to load sudoku file '{}': {}", path.display(), err.pretty())), vec![ *sudoku ]);
+            }
+        }
+        assert!(matches!(split_sudokus(&sudokus, &dir, FileType::Markdown), Err(SaveError::UnsupportedType { ftype: FileType::Markdown })));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[cfg(feature = "net")]
+    #[test]
+    fn test_load_sudoku_from_url() {
+        use std::io::Write as _;
+        use std::net::{SocketAddr, TcpListener, TcpStream};
+        use std::thread::{self, JoinHandle};
+
+        // Serve the collection example exactly once on some free port
+        let listener: TcpListener = TcpListener::bind("127.0.0.1:0").unwrap_or_else(|err| panic!("Failed to bind mock server: {err}"));
+        let addr: SocketAddr = listener.local_addr().unwrap();
+        let server: JoinHandle<()> = thread::spawn(move || {
+            let raw: String = std::fs::read_to_string("./tests/example.sdm").unwrap_or_else(|err| panic!("Failed to read './tests/example.sdm': {err}"));
+            let (mut stream, _): (TcpStream, _) = listener.accept().unwrap_or_else(|err| panic!("Failed to accept connection: {err}"));
+
+            // Read the request until the end of its headers (we don't care what's in it)
+            let mut req: Vec<u8> = vec![];
+            let mut buf: [u8; 1024] = [0; 1024];
+            while !req.windows(4).any(|w| w == b"\r\n\r\n") {
+                let n: usize = stream.read(&mut buf).unwrap_or_else(|err| panic!("Failed to read request: {err}"));
+                if n == 0 { break; }
+                req.extend_from_slice(&buf[..n]);
+            }
+
+            // Send the file back
+            write!(stream, "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}", raw.len(), raw).unwrap_or_else(|err| panic!("Failed to write response: {err}"));
+        });
+
+        // Load it as a URL
+        let sudokus: Vec<Sudoku> = match load_sudoku(format!("http://{addr}/example.sdm")) {
+            Ok(sudokus) => sudokus,
+            Err(err)    => { panic!("Failed to download sudoku file 'http://{addr}/example.sdm': {}", err.pretty()); },
+        };
+        server.join().unwrap();
+
+        // Assert it is what we expect
+        assert_eq!(sudokus, load_sudoku("./tests/example.sdm").unwrap_or_else(|err| panic!("Failed to parse sudoku file './tests/example.sdm': {}", err.pretty())));
     }
 }
 
@@ -178,17 +530,22 @@ pub enum LoadError {
 
     /// Failed to open a file.
     FileOpen { path: PathBuf, err: std::io::Error },
-    /// Failed to parse a file with serde.
-    FileParse { ftype: FileType, path: PathBuf, err: Box<dyn Error> },
+    /// Failed to download a file.
+    #[cfg(feature = "net")]
+    Download { url: String, err: reqwest::Error },
+    /// Failed to parse a file.
+    FileParse { ftype: FileType, path: PathBuf, err: ParseError },
 }
 impl Display for LoadError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
         use LoadError::*;
         match self {
             NoExtension { path }           => write!(f, "Given file path '{}' has no extension; cannot deduce type (specify it manually using '--file-type')", path.display()),
-            UnknownExtension { path, ext } => write!(f, "Extension '{}' in given file path '{}' is unknown; cannot deduce type (specify it manually using '--file-type')", ext.to_string_lossy(), path.display()),
+            UnknownExtension { path, ext } => write!(f, "Extension '{}' in given file path '{}' is unknown; cannot deduce type (supported are {}; or specify it manually using '--file-type')", ext.to_string_lossy(), path.display(), FileType::EXTENSIONS.iter().map(|(ext, _)| format!("'.{ext}'")).collect::<Vec<String>>().join(", ")),
 
             FileOpen { path, .. }         => write!(f, "Failed to open file '{}'", path.display()),
+            #[cfg(feature = "net")]
+            Download { url, .. }          => write!(f, "Failed to download file '{url}'"),
             FileParse { ftype, path, .. } => write!(f, "Failed to parse file '{}' as a {} file", path.display(), ftype),
         }
     }
@@ -201,12 +558,132 @@ impl Error for LoadError {
             UnknownExtension { .. } => None,
 
             FileOpen { err, .. }  => Some(err),
-            FileParse { err, .. } => Some(&**err),
+            #[cfg(feature = "net")]
+            Download { err, .. }  => Some(err),
+            FileParse { err, .. } => Some(err),
+        }
+    }
+}
+
+/// Describes what can happen when saving Sudokus
+#[derive(Debug)]
+pub enum SaveError {
+    /// No extension found.
+    NoExtension { path: PathBuf },
+    /// No known extension given.
+    UnknownExtension { path: PathBuf, ext: OsString },
+    /// The given file type cannot be written.
+    UnsupportedType { ftype: FileType },
+
+    /// Failed to create the output directory.
+    DirCreate { path: PathBuf, err: std::io::Error },
+    /// Failed to create a file.
+    FileCreate { path: PathBuf, err: std::io::Error },
+    /// Failed to write to a file.
+    FileWrite { path: PathBuf, err: std::io::Error },
+}
+impl Display for SaveError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        use SaveError::*;
+        match self {
+            NoExtension { path }           => write!(f, "Given file path '{}' has no extension; cannot deduce type", path.display()),
+            UnknownExtension { path, ext } => write!(f, "Extension '{}' in given file path '{}' is unknown; cannot deduce type", ext.to_string_lossy(), path.display()),
+            UnsupportedType { ftype }      => write!(f, "Writing {ftype} files is not supported"),
+
+            DirCreate { path, .. }  => write!(f, "Failed to create directory '{}'", path.display()),
+            FileCreate { path, .. } => write!(f, "Failed to create file '{}'", path.display()),
+            FileWrite { path, .. }  => write!(f, "Failed to write to file '{}'", path.display()),
+        }
+    }
+}
+impl Error for SaveError {
+    fn source(&self) -> Option<&(dyn 'static + Error)> {
+        use SaveError::*;
+        match self {
+            NoExtension { .. }      => None,
+            UnknownExtension { .. } => None,
+            UnsupportedType { .. }  => None,
+
+            DirCreate { err, .. }  => Some(err),
+            FileCreate { err, .. } => Some(err),
+            FileWrite { err, .. }  => Some(err),
+        }
+    }
+}
+
+/// Describes how a file failed to parse, with the specific error of every [`FileType`].
+/// 
+/// This is transparent, i.e., it shows as the nested error.
+#[derive(Debug)]
+pub enum ParseError {
+    /// Failed to parse a [JSON](FileType::Json) file as a [`Sudoku`] struct.
+    Json { err: serde_json::Error },
+    /// Failed to parse a [JSON](FileType::Json) file as a compact string.
+    JsonCompact { err: CompactParseError },
+    /// Failed to parse a [CSV](FileType::Csv) file.
+    Csv { err: CsvError },
+
+    /// Failed to parse a [Sudoku Puzzle](FileType::SudokuPuzzle) file.
+    SudokuPuzzle { err: SudokuPuzzleError },
+    /// Failed to parse a [Plain Grid](FileType::PlainGrid) file.
+    PlainGrid { err: SudokuPuzzleError },
+    /// Failed to parse a [Sudoku Puzzle Progress](FileType::SudokuPuzzleProgress) file.
+    SudokuPuzzleProgress { err: SudokuPuzzleProgressError },
+    /// Failed to parse a [Sudoku Puzzle Collection](FileType::SudokuPuzzleCollection) file.
+    SudokuPuzzleCollection { err: SudokuPuzzleCollectionError },
+    /// Failed to parse a [Simple Sudoku](FileType::SimpleSudoku) file.
+    SimpleSudoku { err: SimpleSudokuError },
+    /// Failed to parse a [Simple Sudoku (New Style)](FileType::SimpleSudokuNew) file.
+    SimpleSudokuNew { err: SimpleSudokuNewError },
+    /// Failed to parse a [Simple Sudoku (Old Style)](FileType::SimpleSudokuOld) file.
+    SimpleSudokuOld { err: SimpleSudokuOldError },
+    /// Failed to parse an [OpenSudoku](FileType::OpenSudoku) file.
+    OpenSudoku { err: OpenSudokuError },
+    /// Failed to parse a [Markdown](FileType::Markdown) file.
+    Markdown { err: MarkdownError },
+}
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        use ParseError::*;
+        match self {
+            Json { err }        => Display::fmt(err, f),
+            JsonCompact { err } => Display::fmt(err, f),
+            Csv { err }         => Display::fmt(err, f),
+
+            SudokuPuzzle { err }           => Display::fmt(err, f),
+            PlainGrid { err }              => Display::fmt(err, f),
+            SudokuPuzzleProgress { err }   => Display::fmt(err, f),
+            SudokuPuzzleCollection { err } => Display::fmt(err, f),
+            SimpleSudoku { err }           => Display::fmt(err, f),
+            SimpleSudokuNew { err }        => Display::fmt(err, f),
+            SimpleSudokuOld { err }        => Display::fmt(err, f),
+            OpenSudoku { err }             => Display::fmt(err, f),
+            Markdown { err }               => Display::fmt(err, f),
+        }
+    }
+}
+impl Error for ParseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        use ParseError::*;
+        match self {
+            Json { err }        => err.source(),
+            JsonCompact { err } => err.source(),
+            Csv { err }         => err.source(),
+
+            SudokuPuzzle { err }           => err.source(),
+            PlainGrid { err }              => err.source(),
+            SudokuPuzzleProgress { err }   => err.source(),
+            SudokuPuzzleCollection { err } => err.source(),
+            SimpleSudoku { err }           => err.source(),
+            SimpleSudokuNew { err }        => err.source(),
+            SimpleSudokuOld { err }        => err.source(),
+            OpenSudoku { err }             => err.source(),
+            Markdown { err }               => err.source(),
         }
     }
 }
 
-/// Describes what can happen when loading [Sudoku Puzzle](FileType::SudokuPuzzle) [`Sudoku`]s.
+/// Describes what can happen when loading [Sudoku Puzzle](FileType::SudokuPuzzle) (or [Plain Grid](FileType::PlainGrid)) [`Sudoku`]s.
 #[derive(Debug)]
 pub enum SudokuPuzzleError {
     /// Failed to read the input file.
@@ -296,7 +773,9 @@ pub enum SudokuPuzzleCollectionError {
     /// Failed to read the input file.
     FileRead { err: std::io::Error },
 
-    /// Got too many columns.
+    /// Got too few cells.
+    TooFewCells { line: usize, got: usize },
+    /// Got too many cells.
     TooManyCells { line: usize, got: usize },
     /// Got an illegal character for a cell.
     IllegalCellChar { line: usize, cell: usize, got: String },
@@ -307,6 +786,7 @@ impl Display for SudokuPuzzleCollectionError {
         match self {
             FileRead { .. } => write!(f, "Failed to read input file"),
 
+            TooFewCells { line, got }           => write!(f, "Sudoku on line {line} has too few cells (got {got}, expected 81)"),
             TooManyCells { line, got }          => write!(f, "Sudoku on line {line} has too many cells (got {got}, expected 81)"),
             IllegalCellChar { line, cell, got } => write!(f, "Encountered illegal cell character '{got}' in line {line}, cell {cell}"),
         }
@@ -318,6 +798,7 @@ impl Error for SudokuPuzzleCollectionError {
         match self {
             FileRead { err } => Some(err),
 
+            TooFewCells { .. }     => None,
             TooManyCells { .. }    => None,
             IllegalCellChar { .. } => None,
         }
@@ -327,17 +808,22 @@ impl Error for SudokuPuzzleCollectionError {
 /// Describes what can happen when loading [Simple Sudoku](FileType::SimpleSudoku) [`Sudoku`]s.
 #[derive(Debug)]
 pub enum SimpleSudokuError {
-    /// Failed to seek the file in between.
-    FileSeek { err: std::io::Error },
-    /// We failed to parse it in either way
-    ParseFailure { new: SimpleSudokuNewError, old: SimpleSudokuOldError },
+    /// Failed to read the input file.
+    FileRead { err: std::io::Error },
+
+    /// The file looked like the new style, but failed to parse as such.
+    New { err: SimpleSudokuNewError },
+    /// The file looked like the old style, but failed to parse as such.
+    Old { err: SimpleSudokuOldError },
 }
 impl Display for SimpleSudokuError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
         use SimpleSudokuError::*;
         match self {
-            FileSeek { .. }           => writeln!(f, "Failed to reset file handle"),
-            ParseFailure { new, old } => writeln!(f, "Failed to parse Sudoku file as either old-style or new-style SimpleSudoku\n\nNew-style error: {new}\n\nOld-style error: {old}\n"),
+            FileRead { .. } => write!(f, "Failed to read input file"),
+
+            New { .. } => write!(f, "Failed to parse Sudoku file as new-style SimpleSudoku"),
+            Old { .. } => write!(f, "Failed to parse Sudoku file as old-style SimpleSudoku"),
         }
     }
 }
@@ -345,8 +831,10 @@ impl Error for SimpleSudokuError {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
         use SimpleSudokuError::*;
         match self {
-            FileSeek { err }    => Some(err),
-            ParseFailure { .. } => None,
+            FileRead { err } => Some(err),
+
+            New { err } => Some(err),
+            Old { err } => Some(err),
         }
     }
 }
@@ -434,31 +922,178 @@ impl Error for SimpleSudokuOldError {
     }
 }
 
+/// Describes what can happen when loading [OpenSudoku](FileType::OpenSudoku) [`Sudoku`]s.
+#[derive(Debug)]
+pub enum OpenSudokuError {
+    /// Failed to read the input file.
+    FileRead { err: std::io::Error },
+
+    /// The file was not valid XML.
+    Xml { err: quick_xml::Error },
+    /// A `<game>`-element did not have a `data`-attribute.
+    MissingData { game: usize },
+    /// Failed to parse the puzzle in a `<game>`-element.
+    IllegalPuzzle { game: usize, err: CompactParseError },
+}
+impl Display for OpenSudokuError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        use OpenSudokuError::*;
+        match self {
+            FileRead { .. } => write!(f, "Failed to read input file"),
+
+            Xml { .. }                 => write!(f, "Failed to parse input file as XML"),
+            MissingData { game }       => write!(f, "Game {game} does not have a 'data' attribute"),
+            IllegalPuzzle { game, .. } => write!(f, "Failed to parse puzzle of game {game}"),
+        }
+    }
+}
+impl Error for OpenSudokuError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        use OpenSudokuError::*;
+        match self {
+            FileRead { err } => Some(err),
+
+            Xml { err }               => Some(err),
+            MissingData { .. }        => None,
+            IllegalPuzzle { err, .. } => Some(err),
+        }
+    }
+}
+
+/// Describes what can happen when loading [Markdown](FileType::Markdown) [`Sudoku`]s.
+#[derive(Debug)]
+pub enum MarkdownError {
+    /// Failed to read the input file.
+    FileRead { err: std::io::Error },
+
+    /// Failed to parse the grid in a code block.
+    IllegalGrid { line: usize, err: SudokuPuzzleError },
+}
+impl Display for MarkdownError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        use MarkdownError::*;
+        match self {
+            FileRead { .. } => write!(f, "Failed to read input file"),
+
+            IllegalGrid { line, .. } => write!(f, "Failed to parse grid in code block starting on line {line}"),
+        }
+    }
+}
+impl Error for MarkdownError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        use MarkdownError::*;
+        match self {
+            FileRead { err } => Some(err),
+
+            IllegalGrid { err, .. } => Some(err),
+        }
+    }
+}
+
+/// Describes what can happen when loading [CSV](FileType::Csv) [`Sudoku`]s.
+#[derive(Debug)]
+pub enum CsvError {
+    /// Failed to read the input file.
+    FileRead { err: std::io::Error },
+
+    /// A row did not have both a name and a puzzle column.
+    MissingColumn { line: usize },
+    /// A row had a name with an unterminated quote.
+    UnterminatedQuote { line: usize },
+    /// Failed to parse the puzzle in a row.
+    IllegalPuzzle { line: usize, err: CompactParseError },
+}
+impl Display for CsvError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        use CsvError::*;
+        match self {
+            FileRead { .. } => write!(f, "Failed to read input file"),
+
+            MissingColumn { line }     => write!(f, "Line {line} does not have both a name and a puzzle column"),
+            UnterminatedQuote { line } => write!(f, "Name on line {line} has an unterminated quote"),
+            IllegalPuzzle { line, .. } => write!(f, "Failed to parse puzzle on line {line}"),
+        }
+    }
+}
+impl Error for CsvError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        use CsvError::*;
+        match self {
+            FileRead { err } => Some(err),
+
+            MissingColumn { .. }      => None,
+            UnterminatedQuote { .. }  => None,
+            IllegalPuzzle { err, .. } => Some(err),
+        }
+    }
+}
+
 
 
 
 
 /***** HELPER FUNCTIONS *****/
+/// Strips a leading UTF-8 byte order mark (BOM), which some (Windows) editors write at the start of text files.
+/// 
+/// # Arguments
+/// - `raw`: The text to strip.
+/// 
+/// # Returns
+/// The given `raw` text without a leading BOM.
+#[inline]
+fn strip_bom(raw: &str) -> &str { raw.strip_prefix('\u{FEFF}').unwrap_or(raw) }
+
 /// Parses the [Sudoku Puzzle](FileType::SudokuPuzzle) format.
 /// 
 /// # Arguments
-/// - `handle`: A [`File`]-handle from which we read the puzzle.
+/// - `handle`: A [`Read`]-handle (e.g., a [`File`]) from which we read the puzzle.
 /// 
 /// # Returns
 /// A new [`Sudoku`] read from the given `handle.`
 /// 
 /// # Errors
 /// This function may error if the given `handle` did not contain valid SudokuPuzzle contents.
-fn parse_sudoku_puzzle(handle: &mut File) -> Result<Vec<Sudoku>, SudokuPuzzleError> {
+#[inline]
+fn parse_sudoku_puzzle(handle: &mut impl Read) -> Result<Vec<Sudoku>, SudokuPuzzleError> { parse_sudoku_grid(handle, true).map(|(sudoku, _)| vec![ sudoku ]) }
+
+/// Parses the [Plain Grid](FileType::PlainGrid) format.
+/// 
+/// # Arguments
+/// - `handle`: A [`Read`]-handle (e.g., a [`File`]) from which we read the puzzle.
+/// 
+/// # Returns
+/// A new [`Sudoku`] read from the given `handle.`
+/// 
+/// # Errors
+/// This function may error if the given `handle` did not contain valid PlainGrid contents.
+#[inline]
+fn parse_plain_grid(handle: &mut impl Read) -> Result<Vec<Sudoku>, SudokuPuzzleError> { parse_sudoku_grid(handle, false).map(|(sudoku, _)| vec![ sudoku ]) }
+
+/// Parses a grid of 9 lines with 9 cells each, where either `0` or `.` means an empty cell.
+/// 
+/// This is shared between the [Sudoku Puzzle](FileType::SudokuPuzzle) and [Plain Grid](FileType::PlainGrid) formats.
+/// 
+/// # Arguments
+/// - `handle`: A [`Read`]-handle (e.g., a [`File`]) from which we read the puzzle.
+/// - `metadata`: Whether to allow Sudoku Puzzle metadata lines starting with `#`.
+/// 
+/// # Returns
+/// A new [`Sudoku`] read from the given `handle`, together with the [`SudokuMetadata`] in it (which is empty if `metadata` is false).
+/// 
+/// # Errors
+/// This function may error if the given `handle` did not contain a valid grid.
+fn parse_sudoku_grid(handle: &mut impl Read, metadata: bool) -> Result<(Sudoku, SudokuMetadata), SudokuPuzzleError> {
     // Read the whole file
     let mut raw: String = String::new();
     if let Err(err) = handle.read_to_string(&mut raw) {
         return Err(SudokuPuzzleError::FileRead{ err });
     }
+    let raw: &str = strip_bom(&raw);
 
     // Read line-by-line to collect the cells
     let mut y: usize = 0;
     let mut rows: [ [ Option<u8>; 9 ]; 9 ] = [ [ None; 9 ]; 9 ];
+    let mut meta: SudokuMetadata = SudokuMetadata::default();
     for (l, line) in raw.split('\n').enumerate() {
         // Ignore whitespace-only lines
         if line.trim().len() == 0 { continue; }
@@ -467,17 +1102,18 @@ fn parse_sudoku_puzzle(handle: &mut File) -> Result<Vec<Sudoku>, SudokuPuzzleErr
         let line_chars: Vec<&str> = line.graphemes(true).collect();
 
         // If the line stars with a comment, it's metadata
-        if line_chars.len() >= 2 && line_chars[0] == "#" {
-            // Analyse the first letter
+        if metadata && line_chars.len() >= 2 && line_chars[0] == "#" {
+            // Analyse the first letter to find where to store the rest
+            let value: Option<String> = Some(line_chars[2..].concat().trim_end().into());
             match line_chars[1] {
                 // Valid characters
-                "A" |
-                "D" |
-                "C" |
-                "B" |
-                "S" |
-                "L" |
-                "U" => { continue; },
+                "A" => { meta.author = value; continue; },
+                "D" => { meta.description = value; continue; },
+                "C" => { meta.comment = value; continue; },
+                "B" => { meta.date = value; continue; },
+                "S" => { meta.source = value; continue; },
+                "L" => { meta.level = value; continue; },
+                "U" => { meta.url = value; continue; },
 
                 // The rest is unknown
                 marker => { return Err(SudokuPuzzleError::UnknownMetadata { line: l + 1, marker: marker.into() }); },
@@ -489,9 +1125,9 @@ fn parse_sudoku_puzzle(handle: &mut File) -> Result<Vec<Sudoku>, SudokuPuzzleErr
         let mut row: [ Option<u8>; 9 ] = [ None; 9 ];
         for (x, c) in line_chars.into_iter().enumerate() {
             // Mark it if it's a number
-            if c.len() == 1 && c.chars().next().unwrap() >= '0' && c.chars().next().unwrap() <= '9' {
+            if c.len() == 1 && c.chars().next().unwrap() >= '1' && c.chars().next().unwrap() <= '9' {
                 row[x] = Some(u8::from_str(c).unwrap());
-            } else if c != "." {
+            } else if c != "." && c != "0" {
                 return Err(SudokuPuzzleError::IllegalCellChar { line: l + 1, col: x + 1, got: c.into() });
             }
         }
@@ -503,27 +1139,28 @@ fn parse_sudoku_puzzle(handle: &mut File) -> Result<Vec<Sudoku>, SudokuPuzzleErr
     }
 
     // Done!
-    Ok(vec![ Sudoku::with_values(rows) ])
+    Ok((Sudoku::with_values(rows), meta))
 }
 
 /// Parses the [Sudoku Puzzle Progress](FileType::SudokuPuzzleProgress) format.
 /// 
 /// # Arguments
-/// - `handle`: A [`File`]-handle from which we read the puzzle.
+/// - `handle`: A [`Read`]-handle (e.g., a [`File`]) from which we read the puzzle.
 /// 
 /// # Returns
 /// A new [`Sudoku`] read from the given `handle.`
 /// 
 /// # Errors
 /// This function may error if the given `handle` did not contain valid SudokuPuzzleProgress contents.
-fn parse_sudoku_puzzle_progress(handle: &mut File) -> Result<Vec<Sudoku>, SudokuPuzzleProgressError> {
+fn parse_sudoku_puzzle_progress(handle: &mut impl Read) -> Result<Vec<Sudoku>, SudokuPuzzleProgressError> {
     // Read the whole file
     let mut raw: String = String::new();
     if let Err(err) = handle.read_to_string(&mut raw) {
         return Err(SudokuPuzzleProgressError::FileRead{ err });
     }
+    let raw: &str = strip_bom(&raw);
 
-    // Reads the lines, separated by spaces
+    // Reads the lines, separated by commas (as some tools export) or else any whitespace
     let mut y: usize = 0;
     let mut rows: [ [ Option<u8>; 9 ]; 9 ] = [ [ None; 9 ]; 9 ];
     for (l, line) in raw.split('\n').enumerate() {
@@ -532,7 +1169,8 @@ fn parse_sudoku_puzzle_progress(handle: &mut File) -> Result<Vec<Sudoku>, Sudoku
 
         // Parse 9 cells
         let mut row: [ Option<u8>; 9 ] = [ None; 9 ];
-        for (x, c) in line.split(' ').enumerate() {
+        let cells: Vec<&str> = if line.contains(',') { line.split(',').map(str::trim).collect() } else { line.split_whitespace().collect() };
+        for (x, c) in cells.into_iter().enumerate() {
             // Split into logical graphemes
             let c_chars: Vec<&str> = c.graphemes(true).collect();
             if c_chars.is_empty() { return Err(SudokuPuzzleProgressError::EmptyCell { line: l + 1, cell: x + 1 }); }
@@ -571,47 +1209,53 @@ fn parse_sudoku_puzzle_progress(handle: &mut File) -> Result<Vec<Sudoku>, Sudoku
 
 /// Parses the [Sudoku Puzzle Collection](FileType::SudokuPuzzleCollection) format.
 /// 
-/// This format may contain multiple sudoku's.
+/// This format may contain multiple sudoku's. The file is read line-by-line, such that reading stops as soon as `max` Sudokus have been found.
 /// 
 /// # Arguments
-/// - `handle`: A [`File`]-handle from which we read the puzzle.
+/// - `handle`: A [`Read`]-handle (e.g., a [`File`]) from which we read the puzzle.
+/// - `max`: If given, the maximum number of Sudokus to read.
 /// 
 /// # Returns
 /// A new [`Sudoku`] read from the given `handle.`
 /// 
 /// # Errors
 /// This function may error if the given `handle` did not contain valid SudokuPuzzleCollection contents.
-fn parse_sudoku_puzzle_collection(handle: &mut File) -> Result<Vec<Sudoku>, SudokuPuzzleCollectionError> {
-    // Read the whole file
-    let mut raw: String = String::new();
-    if let Err(err) = handle.read_to_string(&mut raw) {
-        return Err(SudokuPuzzleCollectionError::FileRead{ err });
-    }
-
-    // Read the lines (one Sudoku per line)
+fn parse_sudoku_puzzle_collection(handle: &mut impl Read, max: Option<usize>) -> Result<Vec<Sudoku>, SudokuPuzzleCollectionError> {
+    // Read the lines (one or more Sudokus per line, separated by semicolons and/or whitespace)
     let mut sudokus: Vec<Sudoku> = vec![];
-    for (l, line) in raw.split('\n').enumerate() {
-        // Read exactly 81 characters
-        let cells: Vec<&str> = line.graphemes(true).collect();
-        if cells.len() != 81 { return Err(SudokuPuzzleCollectionError::TooManyCells { line: l + 1, got: cells.len() }); }
-
-        // Parse all as single-digit numbers
-        let mut rows: [ [ Option<u8>; 9 ]; 9 ] = [ [ None; 9 ]; 9 ];
-        for (i, cell) in cells.into_iter().enumerate() {
-            if cell.len() == 1 && cell.chars().next().unwrap() >= '0' && cell.chars().next().unwrap() <= '9' {
-                let value: u8 = u8::from_str(cell).unwrap();
-                if value > 0 {
-                    rows[i / 9][i % 9] = Some(value);
+    for (l, line) in BufReader::new(handle).lines().enumerate() {
+        let line: String = match line {
+            Ok(line) => line,
+            Err(err) => { return Err(SudokuPuzzleCollectionError::FileRead{ err }); },
+        };
+        let line: &str = if l == 0 { strip_bom(&line) } else { &line };
+        for chunk in line.split(|c: char| c == ';' || c.is_whitespace()).filter(|c| !c.is_empty()) {
+            // Stop once we have enough
+            if max.map(|max| sudokus.len() >= max).unwrap_or(false) { return Ok(sudokus); }
+
+            // Read exactly 81 characters
+            let cells: Vec<&str> = chunk.graphemes(true).collect();
+            if cells.len() < 81 { return Err(SudokuPuzzleCollectionError::TooFewCells { line: l + 1, got: cells.len() }); }
+            if cells.len() > 81 { return Err(SudokuPuzzleCollectionError::TooManyCells { line: l + 1, got: cells.len() }); }
+
+            // Parse all as single-digit numbers
+            let mut rows: [ [ Option<u8>; 9 ]; 9 ] = [ [ None; 9 ]; 9 ];
+            for (i, cell) in cells.into_iter().enumerate() {
+                if cell.len() == 1 && cell.chars().next().unwrap() >= '0' && cell.chars().next().unwrap() <= '9' {
+                    let value: u8 = u8::from_str(cell).unwrap();
+                    if value > 0 {
+                        rows[i / 9][i % 9] = Some(value);
+                    } else {
+                        rows[i / 9][i % 9] = None;
+                    }
                 } else {
-                    rows[i / 9][i % 9] = None;
+                    return Err(SudokuPuzzleCollectionError::IllegalCellChar { line: l + 1, cell: i + 1, got: cell.into() });
                 }
-            } else {
-                return Err(SudokuPuzzleCollectionError::IllegalCellChar { line: l + 1, cell: i + 1, got: cell.into() });
             }
-        }
 
-        // Store the sudoku
-        sudokus.push(Sudoku::with_values(rows));
+            // Store the sudoku
+            sudokus.push(Sudoku::with_values(rows));
+        }
     }
 
     // Ok done!
@@ -620,47 +1264,49 @@ fn parse_sudoku_puzzle_collection(handle: &mut File) -> Result<Vec<Sudoku>, Sudo
 
 /// Parses the [Simple Sudoku](FileType::SimpleSudoku) format.
 /// 
+/// The style is decided by peeking at the contents: if the fourth line is a `-----------` separator or any line contains a `|`, it's parsed as the [new style](FileType::SimpleSudokuNew), and otherwise as the [old style](FileType::SimpleSudokuOld).
+/// 
 /// # Arguments
-/// - `handle`: A [`File`]-handle from which we read the puzzle.
+/// - `handle`: A [`Read`]-handle (e.g., a [`File`]) from which we read the puzzle.
 /// 
 /// # Returns
 /// A new [`Sudoku`] read from the given `handle.`
 /// 
 /// # Errors
 /// This function may error if the given `handle` did not contain valid SimpleSudoku contents.
-fn parse_simple_sudoku(handle: &mut File) -> Result<Vec<Sudoku>, SimpleSudokuError> {
-    // Attempt to parse as new Sudoku
-    let new_err: SimpleSudokuNewError = match parse_simple_sudoku_new(handle) {
-        Ok(sudokus) => { return Ok(sudokus); },
-        Err(err)    => err,
-    };
-
-    // Reset the handle
-    if let Err(err) = handle.seek(SeekFrom::Start(0)) { return Err(SimpleSudokuError::FileSeek { err }); }
+fn parse_simple_sudoku(handle: &mut impl Read) -> Result<Vec<Sudoku>, SimpleSudokuError> {
+    // Read the whole file
+    let mut raw: String = String::new();
+    if let Err(err) = handle.read_to_string(&mut raw) {
+        return Err(SimpleSudokuError::FileRead{ err });
+    }
 
-    // Try again with the old style
-    match parse_simple_sudoku_old(handle) {
-        Ok(sudokus) => Ok(sudokus),
-        Err(err)    => Err(SimpleSudokuError::ParseFailure { new: new_err, old: err }),
+    // Peek at the contents to decide the style, then parse it as such
+    let new_style: bool = strip_bom(&raw).split('\n').nth(3).map(|line| line.trim_end() == "-----------").unwrap_or(false) || raw.contains('|');
+    if new_style {
+        parse_simple_sudoku_new(&mut raw.as_bytes()).map_err(|err| SimpleSudokuError::New { err })
+    } else {
+        parse_simple_sudoku_old(&mut raw.as_bytes()).map_err(|err| SimpleSudokuError::Old { err })
     }
 }
 
 /// Parses the [Simple Sudoku (New Style)](FileType::SimpleSudokuNew) format.
 /// 
 /// # Arguments
-/// - `handle`: A [`File`]-handle from which we read the puzzle.
+/// - `handle`: A [`Read`]-handle (e.g., a [`File`]) from which we read the puzzle.
 /// 
 /// # Returns
 /// A new [`Sudoku`] read from the given `handle.`
 /// 
 /// # Errors
 /// This function may error if the given `handle` did not contain valid SimpleSudokuNew contents.
-fn parse_simple_sudoku_new(handle: &mut File) -> Result<Vec<Sudoku>, SimpleSudokuNewError> {
+fn parse_simple_sudoku_new(handle: &mut impl Read) -> Result<Vec<Sudoku>, SimpleSudokuNewError> {
     // Read the whole file
     let mut raw: String = String::new();
     if let Err(err) = handle.read_to_string(&mut raw) {
         return Err(SimpleSudokuNewError::FileRead{ err });
     }
+    let raw: &str = strip_bom(&raw);
 
     // Read the lines
     let mut y: usize = 0;
@@ -709,24 +1355,28 @@ fn parse_simple_sudoku_new(handle: &mut File) -> Result<Vec<Sudoku>, SimpleSudok
 /// Parses the [Simple Sudoku (Old Style)](FileType::SimpleSudokuOld) format.
 /// 
 /// # Arguments
-/// - `handle`: A [`File`]-handle from which we read the puzzle.
+/// - `handle`: A [`Read`]-handle (e.g., a [`File`]) from which we read the puzzle.
 /// 
 /// # Returns
 /// A new [`Sudoku`] read from the given `handle.`
 /// 
 /// # Errors
 /// This function may error if the given `handle` did not contain valid SimpleSudokuOld contents.
-fn parse_simple_sudoku_old(handle: &mut File) -> Result<Vec<Sudoku>, SimpleSudokuOldError> {
+fn parse_simple_sudoku_old(handle: &mut impl Read) -> Result<Vec<Sudoku>, SimpleSudokuOldError> {
     // Read the whole file
     let mut raw: String = String::new();
     if let Err(err) = handle.read_to_string(&mut raw) {
         return Err(SimpleSudokuOldError::FileRead{ err });
     }
+    let raw: &str = strip_bom(&raw);
 
     // Read the lines
     let mut y: usize = 0;
     let mut rows: [ [ Option<u8>; 9 ]; 9 ] = [ [ None; 9 ]; 9 ];
     for (l, line) in raw.split('\n').enumerate() {
+        // Ignore whitespace-only lines (e.g., a trailing newline)
+        if line.trim().is_empty() { continue; }
+
         // Otherwise, simply parse nine numbers
         let line_chars: Vec<&str> = line.graphemes(true).collect();
         if line_chars.len() != 9 { return Err(SimpleSudokuOldError::TooManyCols { line: l + 1 }); }
@@ -734,10 +1384,10 @@ fn parse_simple_sudoku_old(handle: &mut File) -> Result<Vec<Sudoku>, SimpleSudok
         // Parse 'em
         let mut row: [ Option<u8>; 9 ] = [ None; 9 ];
         for (x, c) in line_chars.into_iter().enumerate() {
-            // Otherwise, parse as digit
-            if c.len() == 1 && c.chars().next().unwrap() >= '0' && c.chars().next().unwrap() <= '9' {
+            // Otherwise, parse as digit (where either case of 'X' marks an empty cell)
+            if c.len() == 1 && c.chars().next().unwrap() >= '1' && c.chars().next().unwrap() <= '9' {
                 row[x] = Some(u8::from_str(c).unwrap());
-            } else if c != "X" {
+            } else if c != "X" && c != "x" {
                 return Err(SimpleSudokuOldError::IllegalCellChar { line: l + 1, col: x + 1, got: c.into() });
             }
         }
@@ -752,6 +1402,301 @@ fn parse_simple_sudoku_old(handle: &mut File) -> Result<Vec<Sudoku>, SimpleSudok
     Ok(vec![ Sudoku::with_values(rows) ])
 }
 
+/// Parses the [OpenSudoku](FileType::OpenSudoku) format.
+/// 
+/// This format may contain multiple sudoku's.
+/// 
+/// # Arguments
+/// - `handle`: A [`Read`]-handle (e.g., a [`File`]) from which we read the puzzles.
+/// 
+/// # Returns
+/// The [`Sudoku`]s of every `<game>`-element read from the given `handle`, in order.
+/// 
+/// # Errors
+/// This function may error if the given `handle` did not contain valid OpenSudoku contents.
+fn parse_open_sudoku(handle: &mut impl Read) -> Result<Vec<Sudoku>, OpenSudokuError> {
+    // Read the whole file
+    let mut raw: String = String::new();
+    if let Err(err) = handle.read_to_string(&mut raw) {
+        return Err(OpenSudokuError::FileRead{ err });
+    }
+    let mut reader: XmlReader<&[u8]> = XmlReader::from_str(strip_bom(&raw));
+
+    // Collect the data of all the games, ignoring anything else
+    let mut sudokus: Vec<Sudoku> = vec![];
+    loop {
+        match reader.read_event() {
+            Ok(XmlEvent::Start(elem)) | Ok(XmlEvent::Empty(elem)) if elem.name().as_ref() == b"game" => {
+                let game: usize = sudokus.len() + 1;
+                let data = match elem.try_get_attribute("data") {
+                    Ok(Some(data)) => data,
+                    Ok(None)       => { return Err(OpenSudokuError::MissingData { game }); },
+                    Err(err)       => { return Err(OpenSudokuError::Xml { err }); },
+                };
+                let data = data.unescape_value().map_err(|err| OpenSudokuError::Xml { err })?;
+                match Sudoku::from_str(data.trim()) {
+                    Ok(sudoku) => sudokus.push(sudoku),
+                    Err(err)   => { return Err(OpenSudokuError::IllegalPuzzle { game, err }); },
+                }
+            },
+            Ok(XmlEvent::Eof) => { break; },
+            Ok(_)             => {},
+            Err(err)          => { return Err(OpenSudokuError::Xml { err }); },
+        }
+    }
+
+    // Ok done!
+    Ok(sudokus)
+}
+
+/// Parses the [Markdown](FileType::Markdown) format.
+/// 
+/// This format may contain multiple sudoku's, one per fenced code block.
+/// 
+/// # Arguments
+/// - `handle`: A [`Read`]-handle (e.g., a [`File`]) from which we read the puzzles.
+/// 
+/// # Returns
+/// The [`Sudoku`]s of every plain code block read from the given `handle`, in order.
+/// 
+/// # Errors
+/// This function may error if we failed to read the `handle` or if any of its plain code blocks did not contain a valid [Plain Grid](FileType::PlainGrid).
+fn parse_markdown(handle: &mut impl Read) -> Result<Vec<Sudoku>, MarkdownError> {
+    // Read the whole file
+    let mut raw: String = String::new();
+    if let Err(err) = handle.read_to_string(&mut raw) {
+        return Err(MarkdownError::FileRead{ err });
+    }
+    let raw: &str = strip_bom(&raw);
+
+    // Collect the contents of the code blocks, remembering where they started and whether they're plain
+    let mut blocks: Vec<(usize, bool, String)> = vec![];
+    let mut block: Option<(usize, bool, String)> = None;
+    for (l, line) in raw.split('\n').enumerate() {
+        match (line.trim_start().strip_prefix("```"), block.take()) {
+            // Opening or closing fences
+            (Some(info), None) => { block = Some((l + 1, matches!(info.trim(), "" | "plain" | "text" | "txt"), String::new())); },
+            (Some(_), Some(b)) => { blocks.push(b); },
+
+            // Lines inside or outside of blocks
+            (None, Some((start, plain, mut contents))) => {
+                contents.push_str(line);
+                contents.push('\n');
+                block = Some((start, plain, contents));
+            },
+            (None, None) => {},
+        }
+    }
+    // Be lenient on a block that isn't closed
+    blocks.extend(block);
+
+    // Parse the plain ones as grids
+    let mut sudokus: Vec<Sudoku> = vec![];
+    for (start, _, contents) in blocks.into_iter().filter(|(_, plain, _)| *plain) {
+        match parse_plain_grid(&mut contents.as_bytes()) {
+            Ok(grid) => sudokus.extend(grid),
+            Err(err) => { return Err(MarkdownError::IllegalGrid { line: start, err }); },
+        }
+    }
+
+    // Ok done!
+    Ok(sudokus)
+}
+
+/// Parses the [CSV](FileType::Csv) format.
+/// 
+/// This format may contain multiple sudoku's, each with a name.
+/// 
+/// # Arguments
+/// - `handle`: A [`Read`]-handle (e.g., a [`File`]) from which we read the puzzles.
+/// 
+/// # Returns
+/// A list of the names and [`Sudoku`]s read from the given `handle`, in order.
+/// 
+/// # Errors
+/// This function may error if the given `handle` did not contain valid CSV contents.
+fn parse_sudoku_csv(handle: &mut impl Read) -> Result<Vec<(String, Sudoku)>, CsvError> {
+    // Read the whole file
+    let mut raw: String = String::new();
+    if let Err(err) = handle.read_to_string(&mut raw) {
+        return Err(CsvError::FileRead{ err });
+    }
+    let raw: &str = strip_bom(&raw);
+
+    // Read the lines, skipping the header
+    let mut sudokus: Vec<(String, Sudoku)> = vec![];
+    for (l, line) in raw.lines().enumerate().filter(|(_, line)| !line.trim().is_empty()).skip(1) {
+        // Split off the puzzle, which never contains commas itself
+        let (name, puzzle): (&str, &str) = match line.rsplit_once(',') {
+            Some(cols) => cols,
+            None       => { return Err(CsvError::MissingColumn { line: l + 1 }); },
+        };
+
+        // Unquote the name if necessary
+        let name: &str = name.trim();
+        let name: String = if let Some(quoted) = name.strip_prefix('"') {
+            match quoted.strip_suffix('"') {
+                Some(quoted) => quoted.replace("\"\"", "\""),
+                None         => { return Err(CsvError::UnterminatedQuote { line: l + 1 }); },
+            }
+        } else {
+            name.into()
+        };
+
+        // Parse the puzzle itself
+        let puzzle: &str = puzzle.trim().trim_matches('"');
+        match Sudoku::from_str(puzzle) {
+            Ok(sudoku) => sudokus.push((name, sudoku)),
+            Err(err)   => { return Err(CsvError::IllegalPuzzle { line: l + 1, err }); },
+        }
+    }
+
+    // Done!
+    Ok(sudokus)
+}
+
+/// Returns the given path as a URL if it is one.
+/// 
+/// # Arguments
+/// - `path`: The path to analyse.
+/// 
+/// # Returns
+/// The path as a string if it starts with `http://` or `https://`, or [`None`] otherwise.
+#[cfg(feature = "net")]
+fn as_url(path: &Path) -> Option<&str> {
+    let path: &str = path.to_str()?;
+    if path.starts_with("http://") || path.starts_with("https://") { Some(path) } else { None }
+}
+
+/// Opens the given path for reading, downloading it first if it's a URL.
+/// 
+/// # Arguments
+/// - `path`: The path of the file to open. If the `net` feature is enabled, this may also be an `http://` or `https://` URL.
+/// 
+/// # Returns
+/// A [`Read`]er over the contents of the file.
+/// 
+/// # Errors
+/// This function errors if we failed to open (or download) the file.
+fn fetch(path: &Path) -> Result<Box<dyn Read>, LoadError> {
+    // Download the file instead if it's a URL
+    #[cfg(feature = "net")]
+    if let Some(url) = as_url(path) {
+        return match reqwest::blocking::get(url).and_then(|res| res.error_for_status()).and_then(|res| res.bytes()) {
+            Ok(raw)  => Ok(Box::new(Cursor::new(raw.to_vec()))),
+            Err(err) => Err(LoadError::Download { url: url.into(), err }),
+        };
+    }
+
+    // Otherwise, open it as a file
+    match File::open(path) {
+        Ok(handle) => Ok(Box::new(handle)),
+        Err(err)   => Err(LoadError::FileOpen { path: path.into(), err }),
+    }
+}
+
+/// Returns the extension of the given path.
+/// 
+/// If the path is a URL (and the `net` feature is enabled), any query or fragment is ignored.
+/// 
+/// # Arguments
+/// - `path`: The path to get the extension of.
+/// 
+/// # Returns
+/// The extension of the path, or [`None`] if it has none.
+fn extension_of(path: &Path) -> Option<&OsStr> {
+    #[cfg(feature = "net")]
+    if let Some(url) = as_url(path) {
+        return Path::new(url.split(['?', '#']).next().unwrap_or(url)).extension();
+    }
+    path.extension()
+}
+
+/// Parses the contents of the given handle as a Sudoku file of the given type.
+/// 
+/// Formats that are read line-by-line (i.e., the [Sudoku Puzzle Collection](FileType::SudokuPuzzleCollection)) stop reading as soon as `max` Sudokus have been found; others are truncated after parsing.
+/// 
+/// # Arguments
+/// - `handle`: A [`Read`]-handle (e.g., a [`File`]) from which we read the puzzle(s).
+/// - `path`: The path where the `handle` came from. Only used for debugging purposes.
+/// - `ftype`: The type of the file. This determines how to parse its contents.
+/// - `max`: If given, the maximum number of Sudokus to return.
+/// 
+/// # Returns
+/// One or more [`Sudoku`]s parsed from the handle (at most `max`).
+/// 
+/// # Errors
+/// This function may error if we failed to read or correctly parse the contents.
+fn parse_sudoku_of_type(handle: &mut impl Read, path: &Path, ftype: FileType, max: Option<usize>) -> Result<Vec<Sudoku>, LoadError> {
+    let mut sudokus: Vec<Sudoku> = match ftype {
+        // Simple serde, either as the struct or as a compact string
+        FileType::Json => {
+            let mut raw: String = String::new();
+            if let Err(err) = handle.read_to_string(&mut raw) {
+                return Err(LoadError::FileParse { ftype, path: path.into(), err: ParseError::Json { err: serde_json::Error::io(err) } });
+            }
+            match serde_json::from_str(strip_bom(&raw)) {
+                Ok(serde_json::Value::String(raw)) => match Sudoku::from_str(&raw) {
+                    Ok(sudoku) => Ok(vec![ sudoku ]),
+                    Err(err)   => Err(LoadError::FileParse { ftype, path: path.into(), err: ParseError::JsonCompact { err } }),
+                },
+                Ok(value) => match serde_json::from_value(value) {
+                    Ok(sudoku) => Ok(vec![ sudoku ]),
+                    Err(err)   => Err(LoadError::FileParse { ftype, path: path.into(), err: ParseError::Json { err } }),
+                },
+                Err(err) => Err(LoadError::FileParse { ftype, path: path.into(), err: ParseError::Json { err } }),
+            }
+        },
+
+        // Specialized formats
+        FileType::Csv => match parse_sudoku_csv(handle) {
+            Ok(sudokus) => Ok(sudokus.into_iter().map(|(_, sudoku)| sudoku).collect()),
+            Err(err)    => Err(LoadError::FileParse { ftype, path: path.into(), err: ParseError::Csv { err } }),
+        },
+
+        FileType::SudokuPuzzle => match parse_sudoku_puzzle(handle) {
+            Ok(sudoku) => Ok(sudoku),
+            Err(err)   => Err(LoadError::FileParse { ftype, path: path.into(), err: ParseError::SudokuPuzzle { err } }),
+        },
+        FileType::PlainGrid => match parse_plain_grid(handle) {
+            Ok(sudoku) => Ok(sudoku),
+            Err(err)   => Err(LoadError::FileParse { ftype, path: path.into(), err: ParseError::PlainGrid { err } }),
+        },
+        FileType::SudokuPuzzleProgress => match parse_sudoku_puzzle_progress(handle) {
+            Ok(sudoku) => Ok(sudoku),
+            Err(err)   => Err(LoadError::FileParse { ftype, path: path.into(), err: ParseError::SudokuPuzzleProgress { err } }),
+        },
+        FileType::SudokuPuzzleCollection => match parse_sudoku_puzzle_collection(handle, max) {
+            Ok(sudoku) => Ok(sudoku),
+            Err(err)   => Err(LoadError::FileParse { ftype, path: path.into(), err: ParseError::SudokuPuzzleCollection { err } }),
+        },
+        FileType::SimpleSudoku => match parse_simple_sudoku(handle) {
+            Ok(sudoku) => Ok(sudoku),
+            Err(err)   => Err(LoadError::FileParse { ftype, path: path.into(), err: ParseError::SimpleSudoku { err } }),
+        },
+        FileType::SimpleSudokuNew => match parse_simple_sudoku_new(handle) {
+            Ok(sudoku) => Ok(sudoku),
+            Err(err)   => Err(LoadError::FileParse { ftype, path: path.into(), err: ParseError::SimpleSudokuNew { err } }),
+        },
+        FileType::SimpleSudokuOld => match parse_simple_sudoku_old(handle) {
+            Ok(sudoku) => Ok(sudoku),
+            Err(err)   => Err(LoadError::FileParse { ftype, path: path.into(), err: ParseError::SimpleSudokuOld { err } }),
+        },
+        FileType::OpenSudoku => match parse_open_sudoku(handle) {
+            Ok(sudoku) => Ok(sudoku),
+            Err(err)   => Err(LoadError::FileParse { ftype, path: path.into(), err: ParseError::OpenSudoku { err } }),
+        },
+        FileType::Markdown => match parse_markdown(handle) {
+            Ok(sudoku) => Ok(sudoku),
+            Err(err)   => Err(LoadError::FileParse { ftype, path: path.into(), err: ParseError::Markdown { err } }),
+        },
+    }?;
+
+    // Only keep as many as we may
+    if let Some(max) = max { sudokus.truncate(max); }
+    Ok(sudokus)
+}
+
 
 
 
@@ -814,6 +1759,8 @@ impl<'e, E: Error> Display for PrettyErrorFormatter<'e, E> {
 /***** LIBRARY FUNCTIONS *****/
 /// Helper function that loads a Sudoku file, automatically deducing its type from the file extension.
 /// 
+/// If the `net` feature is enabled, the `path` may also be an `http://` or `https://` URL, in which case the file is downloaded first.
+/// 
 /// # Arguments
 /// - `path`: The path to the Sudoku file to open.
 /// 
@@ -822,11 +1769,27 @@ impl<'e, E: Error> Display for PrettyErrorFormatter<'e, E> {
 /// 
 /// # Errors
 /// This function may error if we failed to read or correctly parse the file.
-pub fn load_sudoku(path: impl AsRef<Path>) -> Result<Vec<Sudoku>, LoadError> {
+#[inline]
+pub fn load_sudoku(path: impl AsRef<Path>) -> Result<Vec<Sudoku>, LoadError> { load_sudoku_max(path, None) }
+
+/// Helper function that loads a Sudoku file like [`load_sudoku()`], but that stops after the given number of Sudokus.
+/// 
+/// See [`load_sudoku_of_type_max()`] for when this avoids reading the whole file.
+/// 
+/// # Arguments
+/// - `path`: The path to the Sudoku file to open.
+/// - `max`: If given, the maximum number of Sudokus to load.
+/// 
+/// # Returns
+/// At most `max` [`Sudoku`]s parsed from the file.
+/// 
+/// # Errors
+/// This function may error if we failed to read or correctly parse the file.
+pub fn load_sudoku_max(path: impl AsRef<Path>, max: Option<usize>) -> Result<Vec<Sudoku>, LoadError> {
     let path: &Path = path.as_ref();
 
     // Analyse the method of opening
-    let ftype: FileType = if let Some(ext) = path.extension() {
+    let ftype: FileType = if let Some(ext) = extension_of(path) {
         match FileType::from_ext(ext) {
             Some(ftype) => ftype,
             None        => { return Err(LoadError::UnknownExtension { path: path.into(), ext: ext.into() }); },
@@ -836,11 +1799,13 @@ pub fn load_sudoku(path: impl AsRef<Path>) -> Result<Vec<Sudoku>, LoadError> {
     };
 
     // Pass to type-set parsing
-    load_sudoku_of_type(path, ftype)
+    load_sudoku_of_type_max(path, ftype, max)
 }
 
 /// Helper function that loads a Sudoku file of given type.
 /// 
+/// If the `net` feature is enabled, the `path` may also be an `http://` or `https://` URL, in which case the file is downloaded first.
+/// 
 /// # Arguments
 /// - `path`: The path to the Sudoku file to open.
 /// - `ftype`: The type of the file. This determines how to parse its contents.
@@ -850,51 +1815,231 @@ pub fn load_sudoku(path: impl AsRef<Path>) -> Result<Vec<Sudoku>, LoadError> {
 /// 
 /// # Errors
 /// This function may error if we failed to read or correctly parse the file.
-pub fn load_sudoku_of_type(path: impl AsRef<Path>, ftype: FileType) -> Result<Vec<Sudoku>, LoadError> {
+#[inline]
+pub fn load_sudoku_of_type(path: impl AsRef<Path>, ftype: FileType) -> Result<Vec<Sudoku>, LoadError> { load_sudoku_of_type_max(path, ftype, None) }
+
+/// Helper function that loads a Sudoku file of given type like [`load_sudoku_of_type()`], but that stops after the given number of Sudokus.
+/// 
+/// [Sudoku Puzzle Collection](FileType::SudokuPuzzleCollection)s are read line-by-line, and so the rest of the file is not read once `max` Sudokus have been found. Other types are parsed in full, and then truncated.
+/// 
+/// # Arguments
+/// - `path`: The path to the Sudoku file to open.
+/// - `ftype`: The type of the file. This determines how to parse its contents.
+/// - `max`: If given, the maximum number of Sudokus to load.
+/// 
+/// # Returns
+/// At most `max` [`Sudoku`]s parsed from the file.
+/// 
+/// # Errors
+/// This function may error if we failed to read or correctly parse the file.
+pub fn load_sudoku_of_type_max(path: impl AsRef<Path>, ftype: FileType, max: Option<usize>) -> Result<Vec<Sudoku>, LoadError> {
+    let path: &Path = path.as_ref();
+
+    // Open (or download) the file
+    let mut handle: Box<dyn Read> = fetch(path)?;
+
+    // Parse it according to the type
+    parse_sudoku_of_type(&mut handle, path, ftype, max)
+}
+
+/// Helper function that loads a [CSV](FileType::Csv) file of named Sudokus.
+/// 
+/// Unlike [`load_sudoku_of_type()`], this keeps the names of the Sudokus.
+/// 
+/// If the `net` feature is enabled, the `path` may also be an `http://` or `https://` URL, in which case the file is downloaded first.
+/// 
+/// # Arguments
+/// - `path`: The path to the CSV file to open.
+/// 
+/// # Returns
+/// A list of the names and [`Sudoku`]s in the file, in order.
+/// 
+/// # Errors
+/// This function may error if we failed to read or correctly parse the file.
+pub fn load_sudoku_csv(path: impl AsRef<Path>) -> Result<Vec<(String, Sudoku)>, LoadError> {
+    let path: &Path = path.as_ref();
+
+    // Open (or download) the file
+    let mut handle: Box<dyn Read> = fetch(path)?;
+
+    // Parse it
+    parse_sudoku_csv(&mut handle).map_err(|err| LoadError::FileParse { ftype: FileType::Csv, path: path.into(), err: ParseError::Csv { err } })
+}
+
+/// Helper function that loads a Sudoku file like [`load_sudoku()`], but that also returns any metadata in it.
+/// 
+/// Only the [Sudoku Puzzle](FileType::SudokuPuzzle) format has metadata; for the others, it is always [`None`].
+/// 
+/// If the `net` feature is enabled, the `path` may also be an `http://` or `https://` URL, in which case the file is downloaded first.
+/// 
+/// # Arguments
+/// - `path`: The path to the Sudoku file to open.
+/// 
+/// # Returns
+/// One or more [`Sudoku`]s parsed from the file, each with its [`SudokuMetadata`] if the format supports it.
+/// 
+/// # Errors
+/// This function may error if we failed to read or correctly parse the file.
+pub fn load_sudoku_with_metadata(path: impl AsRef<Path>) -> Result<Vec<(Sudoku, Option<SudokuMetadata>)>, LoadError> {
     let path: &Path = path.as_ref();
 
+    // Only the Sudoku Puzzle format needs special treatment
+    match extension_of(path).and_then(FileType::from_ext) {
+        Some(FileType::SudokuPuzzle) => {},
+        _                            => { return Ok(load_sudoku(path)?.into_iter().map(|sudoku| (sudoku, None)).collect()); },
+    }
+
+    // Open (or download) the file
+    let mut handle: Box<dyn Read> = fetch(path)?;
+
+    // Parse it
+    parse_sudoku_grid(&mut handle, true).map(|(sudoku, meta)| vec![ (sudoku, Some(meta)) ]).map_err(|err| LoadError::FileParse { ftype: FileType::SudokuPuzzle, path: path.into(), err: ParseError::SudokuPuzzle { err } })
+}
+
+/// Writes a Sudoku in the [Simple Sudoku (New Style)](FileType::SimpleSudokuNew) format.
+/// 
+/// This is the inverse of parsing that format, i.e., it writes the `|`/`-----------`-separated layout with `.` for empty cells.
+/// 
+/// # Arguments
+/// - `sudoku`: The [`Sudoku`] to write.
+/// - `writer`: The [`Write`]r (e.g., a [`File`]) to write the puzzle to.
+/// 
+/// # Errors
+/// This function errors if we failed to write to the given `writer`.
+pub fn write_simple_sudoku_new(sudoku: &Sudoku, mut writer: impl Write) -> Result<(), std::io::Error> {
+    for (y, row) in sudoku.rows_iter().enumerate() {
+        // Write the separator rows on the fourth and eights lines (and newlines in between)
+        if y > 0 { writeln!(writer)?; }
+        if y == 3 || y == 6 { writeln!(writer, "-----------")?; }
+
+        // Write the cells with column separators
+        for (x, cell) in row.into_iter().enumerate() {
+            if x == 3 || x == 6 { write!(writer, "|")?; }
+            match cell {
+                Some(value) => write!(writer, "{value}")?,
+                None        => write!(writer, ".")?,
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Writes solutions in their [compact](Sudoku::to_compact_string()) form, i.e., one line of 81 cells per solution and nothing else.
+/// 
+/// # Arguments
+/// - `solutions`: The [`Sudoku`]s to write.
+/// - `writer`: The [`Write`]r (e.g., stdout) to write the solutions to.
+/// 
+/// # Errors
+/// This function errors if we failed to write to the given `writer`.
+pub fn write_compact_solutions(solutions: &[Sudoku], mut writer: impl Write) -> Result<(), std::io::Error> {
+    for solution in solutions {
+        writeln!(writer, "{}", solution.to_compact_string())?;
+    }
+    Ok(())
+}
+
+/// Helper function that saves a Sudoku to a file of given type.
+/// 
+/// Only the [JSON](FileType::Json), [Sudoku Puzzle](FileType::SudokuPuzzle), [Plain Grid](FileType::PlainGrid) and [Simple Sudoku](FileType::SimpleSudoku) formats can be written (the latter in its [new style](FileType::SimpleSudokuNew)).
+/// 
+/// # Arguments
+/// - `sudoku`: The [`Sudoku`] to save.
+/// - `path`: The path to the file to write. If it already exists, it is overwritten.
+/// - `ftype`: The type of the file. This determines how to write the Sudoku.
+/// 
+/// # Errors
+/// This function errors if the `ftype` cannot be written, or if we failed to create or write the file.
+pub fn save_sudoku_of_type(sudoku: &Sudoku, path: impl AsRef<Path>, ftype: FileType) -> Result<(), SaveError> {
+    let path: &Path = path.as_ref();
+    if !matches!(ftype, FileType::Json | FileType::SudokuPuzzle | FileType::PlainGrid | FileType::SimpleSudoku | FileType::SimpleSudokuNew) {
+        return Err(SaveError::UnsupportedType { ftype });
+    }
+
     // Open the file
-    let mut handle: File = match File::open(path) {
+    let mut handle: File = match File::create(path) {
         Ok(handle) => handle,
-        Err(err)   => { return Err(LoadError::FileOpen { path: path.into(), err }); },
+        Err(err)   => { return Err(SaveError::FileCreate { path: path.into(), err }); },
     };
 
-    // Parse it according to the type
-    match ftype {
-        // Simple serde
-        FileType::Json => match serde_json::from_reader(handle) {
-            Ok(sudoku) => Ok(vec![ sudoku ]),
-            Err(err)   => Err(LoadError::FileParse { ftype, path: path.into(), err: Box::new(err) }),
+    // Write it in the correct format
+    let res: Result<(), std::io::Error> = match ftype {
+        FileType::Json => serde_json::to_writer_pretty(&mut handle, sudoku).map_err(std::io::Error::from),
+        FileType::SudokuPuzzle | FileType::PlainGrid => {
+            let empty: char = if ftype == FileType::SudokuPuzzle { '.' } else { '0' };
+            sudoku.rows_iter().try_for_each(|row| writeln!(handle, "{}", row.into_iter().map(|cell| cell.map(|v| (b'0' + v) as char).unwrap_or(empty)).collect::<String>()))
         },
+        _ => write_simple_sudoku_new(sudoku, &mut handle),
+    };
+    res.map_err(|err| SaveError::FileWrite { path: path.into(), err })
+}
 
-        // Specialized formats
-        FileType::SudokuPuzzle => match parse_sudoku_puzzle(&mut handle) {
-            Ok(sudoku) => Ok(sudoku),
-            Err(err)   => Err(LoadError::FileParse { ftype, path: path.into(), err: Box::new(err) }),
-        },
-        FileType::SudokuPuzzleProgress => match parse_sudoku_puzzle_progress(&mut handle) {
-            Ok(sudoku) => Ok(sudoku),
-            Err(err)   => Err(LoadError::FileParse { ftype, path: path.into(), err: Box::new(err) }),
-        },
-        FileType::SudokuPuzzleCollection => match parse_sudoku_puzzle_collection(&mut handle) {
-            Ok(sudoku) => Ok(sudoku),
-            Err(err)   => Err(LoadError::FileParse { ftype, path: path.into(), err: Box::new(err) }),
-        },
-        FileType::SimpleSudoku => match parse_simple_sudoku(&mut handle) {
-            Ok(sudoku) => Ok(sudoku),
-            Err(err)   => Err(LoadError::FileParse { ftype, path: path.into(), err: Box::new(err) }),
-        },
-        FileType::SimpleSudokuNew => match parse_simple_sudoku_new(&mut handle) {
-            Ok(sudoku) => Ok(sudoku),
-            Err(err)   => Err(LoadError::FileParse { ftype, path: path.into(), err: Box::new(err) }),
-        },
-        FileType::SimpleSudokuOld => match parse_simple_sudoku_old(&mut handle) {
-            Ok(sudoku) => Ok(sudoku),
-            Err(err)   => Err(LoadError::FileParse { ftype, path: path.into(), err: Box::new(err) }),
-        },
+/// Saves every Sudoku in a collection to its own file in the given directory.
+/// 
+/// The files are named `sudoku_1`, `sudoku_2`, etc., followed by the typical [extension](FileType::extension()) of the chosen type.
+/// 
+/// # Arguments
+/// - `sudokus`: The [`Sudoku`]s to save, e.g., as loaded from a [Sudoku Puzzle Collection](FileType::SudokuPuzzleCollection) file.
+/// - `dir`: The directory to write the files to. It is created if it does not exist yet.
+/// - `ftype`: The type of the files to write (see [`save_sudoku_of_type()`]).
+/// 
+/// # Returns
+/// The paths of the written files, in the same order as `sudokus`.
+/// 
+/// # Errors
+/// This function errors if we failed to create the directory or to save any of the Sudokus.
+pub fn split_sudokus(sudokus: &[Sudoku], dir: impl AsRef<Path>, ftype: FileType) -> Result<Vec<PathBuf>, SaveError> {
+    let dir: &Path = dir.as_ref();
+    if let Err(err) = std::fs::create_dir_all(dir) {
+        return Err(SaveError::DirCreate { path: dir.into(), err });
     }
+
+    // Write them one-by-one
+    let mut paths: Vec<PathBuf> = Vec::with_capacity(sudokus.len());
+    for (i, sudoku) in sudokus.iter().enumerate() {
+        let path: PathBuf = dir.join(format!("sudoku_{}.{}", i + 1, ftype.extension()));
+        save_sudoku_of_type(sudoku, &path, ftype)?;
+        paths.push(path);
+    }
+    Ok(paths)
 }
 
+/// Computes the path to write a converted Sudoku file to, i.e., next to the original but with another extension.
+/// 
+/// # Arguments
+/// - `path`: The path of the original file.
+/// - `index`: If the original file contains multiple Sudokus, the (zero-indexed) index of the one to write. It is added to the file name as `_1`, `_2`, etc.
+/// - `ftype`: The type to convert to. This determines the new [extension](FileType::extension()).
+/// 
+/// # Returns
+/// The path of the converted file, e.g., `puzzle.json` for `puzzle.sdk`.
+pub fn converted_path(path: impl AsRef<Path>, index: Option<usize>, ftype: FileType) -> PathBuf {
+    let path: &Path = path.as_ref();
+    let mut name: OsString = path.file_stem().map(OsString::from).unwrap_or_default();
+    if let Some(index) = index { name.push(format!("_{}", index + 1)); }
+    path.with_file_name(name).with_extension(ftype.extension())
+}
+
+/// Builds the header to show above the solution of a Sudoku, giving some context about the puzzle.
+/// 
+/// # Arguments
+/// - `name`: The name of the Sudoku.
+/// - `puzzle`: The (unsolved) puzzle that was solved.
+/// 
+/// # Returns
+/// A header like `Solution to Sudoku 'name' (36 givens):`.
+pub fn solution_header(name: &str, puzzle: &Sudoku) -> String { format!("Solution to Sudoku '{}' ({} givens):", name, puzzle.givens_count()) }
+
+/// Builds the line that reports how many Sudokus were loaded from a file (see `--count-only`).
+/// 
+/// # Arguments
+/// - `path`: The path of the file that the Sudokus were loaded from.
+/// - `sudokus`: The Sudokus loaded from it.
+/// 
+/// # Returns
+/// A line like `Loaded 8 Sudoku(s) from 'path'`.
+pub fn count_report(path: impl AsRef<Path>, sudokus: &[Sudoku]) -> String { format!("Loaded {} Sudoku(s) from '{}'", sudokus.len(), path.as_ref().display()) }
+
 
 
 